@@ -0,0 +1,17 @@
+/// # All of the wrapped access rules have to hold
+/// The violations of this access rule are the violations of every wrapped rule.
+/// To combine different kinds of rules, wrap them as `Box<dyn AccessRule>`.
+#[derive(Debug, Clone)]
+pub struct AllOf<R> {
+    rules: Vec<R>,
+}
+
+impl<R> AllOf<R> {
+    pub fn new(rules: Vec<R>) -> Self {
+        AllOf { rules }
+    }
+
+    pub fn rules(&self) -> &Vec<R> {
+        &self.rules
+    }
+}
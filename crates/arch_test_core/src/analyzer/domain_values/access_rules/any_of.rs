@@ -0,0 +1,17 @@
+/// # At least one of the wrapped access rules has to hold
+/// The access rule is satisfied as soon as one wrapped rule has no violations.
+/// Otherwise it reports the violations of the wrapped rule with the fewest violations.
+#[derive(Debug, Clone)]
+pub struct AnyOf<R> {
+    rules: Vec<R>,
+}
+
+impl<R> AnyOf<R> {
+    pub fn new(rules: Vec<R>) -> Self {
+        AnyOf { rules }
+    }
+
+    pub fn rules(&self) -> &Vec<R> {
+        &self.rules
+    }
+}
@@ -0,0 +1,65 @@
+use crate::parser::domain_values::ObjectType;
+
+/// # Items have to follow a naming convention
+/// Every item of the `object_type` inside the `layer` has to satisfy the naming `convention`,
+/// e.g. every trait in the `ports` layer has to end with `Port`.
+/// Without an object type every item is checked, without a layer the whole crate is checked.
+/// As layer name it attempts to match either the module name or the parent module name, which is the directory the files were placed in.
+#[derive(Debug, Clone)]
+pub struct ItemNaming {
+    object_type: Option<ObjectType>,
+    layer: Option<String>,
+    convention: NamingConvention,
+}
+
+/// # Condition the name of an item has to satisfy
+#[derive(Debug, Clone)]
+pub enum NamingConvention {
+    StartsWith(String),
+    EndsWith(String),
+    Contains(String),
+}
+
+impl ItemNaming {
+    pub fn new(
+        object_type: Option<ObjectType>,
+        layer: Option<String>,
+        convention: NamingConvention,
+    ) -> Self {
+        ItemNaming {
+            object_type,
+            layer,
+            convention,
+        }
+    }
+
+    pub fn object_type(&self) -> Option<ObjectType> {
+        self.object_type
+    }
+
+    pub fn layer(&self) -> Option<&String> {
+        self.layer.as_ref()
+    }
+
+    pub fn convention(&self) -> &NamingConvention {
+        &self.convention
+    }
+}
+
+impl NamingConvention {
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            NamingConvention::StartsWith(prefix) => name.starts_with(prefix.as_str()),
+            NamingConvention::EndsWith(suffix) => name.ends_with(suffix.as_str()),
+            NamingConvention::Contains(part) => name.contains(part.as_str()),
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            NamingConvention::StartsWith(prefix) => format!("start with `{}`", prefix),
+            NamingConvention::EndsWith(suffix) => format!("end with `{}`", suffix),
+            NamingConvention::Contains(part) => format!("contain `{}`", part),
+        }
+    }
+}
@@ -1,15 +1,27 @@
+pub use self::all_of::AllOf;
+pub use self::any_of::AnyOf;
+pub use self::item_naming::{ItemNaming, NamingConvention};
 pub use self::may_not_access::MayNotAccess;
 pub use self::may_not_be_accessed_by::MayNotBeAccessedBy;
 pub use self::may_only_access::MayOnlyAccess;
 pub use self::may_only_be_accessed_by::MayOnlyBeAccessedBy;
+pub use self::no_item_cyclic_dependencies::{ItemCycleScope, NoItemCyclicDependencies};
 pub use self::no_layer_cyclic_dependencies::NoLayerCyclicDependencies;
 pub use self::no_module_cyclic_dependencies::NoModuleCyclicDependencies;
 pub use self::no_parent_access::NoParentAccess;
+pub use self::not::Not;
+pub use self::within::Within;
 
+mod all_of;
+mod any_of;
+mod item_naming;
 mod may_not_access;
 mod may_not_be_accessed_by;
 mod may_only_access;
 mod may_only_be_accessed_by;
+mod no_item_cyclic_dependencies;
 mod no_layer_cyclic_dependencies;
 mod no_module_cyclic_dependencies;
 mod no_parent_access;
+mod not;
+mod within;
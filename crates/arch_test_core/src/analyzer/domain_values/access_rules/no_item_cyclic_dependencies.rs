@@ -0,0 +1,42 @@
+use std::collections::HashSet;
+
+/// # Forbids cyclic dependencies between items
+/// This access rule forbids cyclic dependencies between individual structs, enums, traits, functions and type aliases.
+/// In contrast to `NoModuleCyclicDependencies` it also detects cycles within the same module.
+///
+/// Example: The struct A holds a `Rc<B>` and B holds a `Rc<A>`.
+/// Or the trait `Port` returns an `Adapter`, which in turn implements `Port`.
+///
+/// The `scope` restricts which cycles are reported, see `ItemCycleScope`.
+#[derive(Debug, Clone)]
+pub struct NoItemCyclicDependencies {
+    scope: ItemCycleScope,
+}
+
+/// # Scope of the `NoItemCyclicDependencies` access rule
+/// As layer name it attempts to match either the module name or the parent module name, which is the directory the files were placed in.
+#[derive(Debug, Clone)]
+pub enum ItemCycleScope {
+    /// Every cycle within the crate is reported
+    Crate,
+    /// Only cycles whose items all belong to the specified layer are reported
+    WithinLayer(String),
+    /// Only cycles that involve the specified layers but are not contained in a single one of them are reported
+    AcrossLayers(HashSet<String>),
+}
+
+impl NoItemCyclicDependencies {
+    pub fn new(scope: ItemCycleScope) -> Self {
+        NoItemCyclicDependencies { scope }
+    }
+
+    pub fn scope(&self) -> &ItemCycleScope {
+        &self.scope
+    }
+}
+
+impl Default for NoItemCyclicDependencies {
+    fn default() -> Self {
+        NoItemCyclicDependencies::new(ItemCycleScope::Crate)
+    }
+}
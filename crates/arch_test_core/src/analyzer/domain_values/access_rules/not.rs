@@ -0,0 +1,17 @@
+/// # The wrapped access rule has to be violated
+/// Useful to assert that a relation does exist, e.g. `Not(MayNotAccess(services, domain_values))`
+/// states that the services layer has to use the domain values.
+#[derive(Debug, Clone)]
+pub struct Not<R> {
+    rule: R,
+}
+
+impl<R> Not<R> {
+    pub fn new(rule: R) -> Self {
+        Not { rule }
+    }
+
+    pub fn rule(&self) -> &R {
+        &self.rule
+    }
+}
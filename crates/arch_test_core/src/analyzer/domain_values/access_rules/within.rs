@@ -0,0 +1,28 @@
+/// # Applies the wrapped access rule only within a subtree of modules
+/// Only violations whose accessing modules all lie inside the `scope` are reported,
+/// violations without any location, e.g. of custom rules, are dropped.
+/// Cycle rules only consider the modules inside the scope and `Not` evaluates its rule on the scope,
+/// see `AccessRule::violations_within`.
+/// The scope is either a fully qualified module path like `crate::analyzer`,
+/// or a name that is matched against the module name and its parent module names like a layer.
+///
+/// This generalises `when_same_parent` to an arbitrary subtree.
+#[derive(Debug, Clone)]
+pub struct Within<R> {
+    scope: String,
+    rule: R,
+}
+
+impl<R> Within<R> {
+    pub fn new(scope: String, rule: R) -> Self {
+        Within { scope, rule }
+    }
+
+    pub fn scope(&self) -> &String {
+        &self.scope
+    }
+
+    pub fn rule(&self) -> &R {
+        &self.rule
+    }
+}
@@ -0,0 +1,44 @@
+use crate::parser::domain_values::{ObjectUse, UseRelation};
+
+/// Violation reported by a custom rule, see `Architecture::with_custom_rule`
+///
+/// The location is optional; a use relation additionally points to the accessed object.
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct CustomViolation {
+    message: String,
+    location: Option<ObjectUse>,
+    use_relation: Option<UseRelation>,
+}
+
+impl CustomViolation {
+    pub fn new(message: String) -> Self {
+        CustomViolation {
+            message,
+            location: None,
+            use_relation: None,
+        }
+    }
+
+    pub fn with_location(mut self, location: ObjectUse) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    pub fn with_use_relation(mut self, use_relation: UseRelation) -> Self {
+        self.location = Some(use_relation.using_object().clone());
+        self.use_relation = Some(use_relation);
+        self
+    }
+
+    pub fn message(&self) -> &String {
+        &self.message
+    }
+
+    pub fn location(&self) -> Option<&ObjectUse> {
+        self.location.as_ref()
+    }
+
+    pub fn use_relation(&self) -> Option<&UseRelation> {
+        self.use_relation.as_ref()
+    }
+}
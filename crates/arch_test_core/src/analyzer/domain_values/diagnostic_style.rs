@@ -0,0 +1,62 @@
+use crate::analyzer::domain_values::Severity;
+
+/// How `RuleViolation::render_with` writes a diagnostic
+///
+/// The severity selects the level, `error` for denied and `warning` for warned rules,
+/// colour adds ANSI escape codes like rustc does on a terminal.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub struct DiagnosticStyle {
+    severity: Severity,
+    color: bool,
+}
+
+impl DiagnosticStyle {
+    pub fn new(severity: Severity, color: bool) -> Self {
+        DiagnosticStyle { severity, color }
+    }
+
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    pub fn color(&self) -> bool {
+        self.color
+    }
+
+    /// Level written in front of the error code, e.g. `error[A0006]`
+    pub fn level(&self) -> &'static str {
+        match self.severity {
+            Severity::Deny => "error",
+            Severity::Warn => "warning",
+            Severity::Allow => "note",
+        }
+    }
+
+    /// Wraps the text in the ANSI escape code if colour is enabled
+    pub(crate) fn paint(&self, text: &str, code: &str) -> String {
+        if self.color {
+            format!("\x1b[{}m{}\x1b[0m", code, text)
+        } else {
+            text.to_owned()
+        }
+    }
+
+    /// ANSI escape code of the level and the primary span, red for errors and yellow for warnings
+    pub(crate) fn level_code(&self) -> &'static str {
+        match self.severity {
+            Severity::Deny => "1;31",
+            Severity::Warn => "1;33",
+            Severity::Allow => "1;32",
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use std::fmt::{Display, Formatter, Write};
+
+/// Minimal JSON document used for the machine readable output, see `Report::to_json`
+///
+/// `Display` writes compact JSON, the alternate form `{:#}` indents it.
+/// With the feature `serde` it converts into a `serde_json::Value`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum JsonValue {
+    Null,
+    Number(i64),
+    String(String),
+    Array(Vec<JsonValue>),
+    /// Keys keep their insertion order
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    /// Object from pairs of key and value
+    pub fn object(entries: Vec<(&str, JsonValue)>) -> Self {
+        JsonValue::Object(
+            entries
+                .into_iter()
+                .map(|(key, value)| (key.to_owned(), value))
+                .collect(),
+        )
+    }
+
+    pub fn string(value: &str) -> Self {
+        JsonValue::String(value.to_owned())
+    }
+
+    pub fn number(value: usize) -> Self {
+        JsonValue::Number(value as i64)
+    }
+
+    /// Adds an entry if the value is an object
+    pub fn with_entry(mut self, key: &str, value: JsonValue) -> Self {
+        if let JsonValue::Object(entries) = &mut self {
+            entries.push((key.to_owned(), value));
+        }
+        self
+    }
+
+    fn write(&self, f: &mut Formatter<'_>, indent: Option<usize>) -> std::fmt::Result {
+        match self {
+            JsonValue::Null => f.write_str("null"),
+            JsonValue::Number(value) => write!(f, "{}", value),
+            JsonValue::String(value) => write_string(f, value),
+            JsonValue::Array(values) => {
+                if values.is_empty() {
+                    return f.write_str("[]");
+                }
+                f.write_char('[')?;
+                for (index, value) in values.iter().enumerate() {
+                    write_separator(f, index, indent)?;
+                    value.write(f, indent.map(|indent| indent + 1))?;
+                }
+                write_closing(f, indent, ']')
+            }
+            JsonValue::Object(entries) => {
+                if entries.is_empty() {
+                    return f.write_str("{}");
+                }
+                f.write_char('{')?;
+                for (index, (key, value)) in entries.iter().enumerate() {
+                    write_separator(f, index, indent)?;
+                    write_string(f, key)?;
+                    f.write_str(if indent.is_some() { ": " } else { ":" })?;
+                    value.write(f, indent.map(|indent| indent + 1))?;
+                }
+                write_closing(f, indent, '}')
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<JsonValue> for serde_json::Value {
+    fn from(json_value: JsonValue) -> Self {
+        match json_value {
+            JsonValue::Null => serde_json::Value::Null,
+            JsonValue::Number(value) => serde_json::Value::from(value),
+            JsonValue::String(value) => serde_json::Value::String(value),
+            JsonValue::Array(values) => {
+                serde_json::Value::Array(values.into_iter().map(serde_json::Value::from).collect())
+            }
+            JsonValue::Object(entries) => serde_json::Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, serde_json::Value::from(value)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl Display for JsonValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let indent = if f.alternate() { Some(0) } else { None };
+        self.write(f, indent)
+    }
+}
+
+fn write_separator(f: &mut Formatter<'_>, index: usize, indent: Option<usize>) -> std::fmt::Result {
+    if index > 0 {
+        f.write_char(',')?;
+    }
+    if let Some(indent) = indent {
+        write!(f, "\n{}", "  ".repeat(indent + 1))?;
+    }
+    Ok(())
+}
+
+fn write_closing(f: &mut Formatter<'_>, indent: Option<usize>, closing: char) -> std::fmt::Result {
+    if let Some(indent) = indent {
+        write!(f, "\n{}", "  ".repeat(indent))?;
+    }
+    f.write_char(closing)
+}
+
+fn write_string(f: &mut Formatter<'_>, value: &str) -> std::fmt::Result {
+    f.write_char('"')?;
+    for character in value.chars() {
+        match character {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            character if (character as u32) < 0x20 => write!(f, "\\u{:04x}", character as u32)?,
+            character => f.write_char(character)?,
+        }
+    }
+    f.write_char('"')
+}
@@ -0,0 +1,112 @@
+use std::fmt::{Display, Formatter};
+
+/// Number of modules and items belonging to a layer
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LayerShare {
+    layer: String,
+    modules: usize,
+    items: usize,
+}
+
+impl LayerShare {
+    pub fn new(layer: String, modules: usize, items: usize) -> Self {
+        LayerShare {
+            layer,
+            modules,
+            items,
+        }
+    }
+
+    pub fn layer(&self) -> &String {
+        &self.layer
+    }
+
+    pub fn modules(&self) -> usize {
+        self.modules
+    }
+
+    pub fn items(&self) -> usize {
+        self.items
+    }
+}
+
+/// How much of a module tree is covered by the layers of an `Architecture`
+///
+/// A module belongs to a layer if its own name or the name of one of its parents equals the layer name,
+/// an item belongs to the layers of its module. Layers may overlap, so the shares do not need to add up.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LayerCoverage {
+    layers: Vec<LayerShare>,
+    total_modules: usize,
+    total_items: usize,
+}
+
+impl LayerCoverage {
+    pub fn new(layers: Vec<LayerShare>, total_modules: usize, total_items: usize) -> Self {
+        LayerCoverage {
+            layers,
+            total_modules,
+            total_items,
+        }
+    }
+
+    /// Shares sorted by layer name
+    pub fn layers(&self) -> &Vec<LayerShare> {
+        &self.layers
+    }
+
+    pub fn total_modules(&self) -> usize {
+        self.total_modules
+    }
+
+    pub fn total_items(&self) -> usize {
+        self.total_items
+    }
+
+    /// Layers that no module belongs to, usually a typo or a renamed module
+    pub fn unmatched_layers(&self) -> Vec<&String> {
+        self.layers
+            .iter()
+            .filter(|share| share.modules == 0)
+            .map(|share| &share.layer)
+            .collect()
+    }
+
+    pub fn module_percentage(&self, share: &LayerShare) -> f64 {
+        percentage(share.modules, self.total_modules)
+    }
+
+    pub fn item_percentage(&self, share: &LayerShare) -> f64 {
+        percentage(share.items, self.total_items)
+    }
+}
+
+impl Display for LayerCoverage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:<20} | {:>16} | {:>16}", "Layer", "Modules", "Items")?;
+        for share in self.layers.iter() {
+            writeln!(
+                f,
+                "{:<20} | {:>6} ({:>5.1}%) | {:>6} ({:>5.1}%)",
+                share.layer,
+                share.modules,
+                self.module_percentage(share),
+                share.items,
+                self.item_percentage(share)
+            )?;
+        }
+        write!(
+            f,
+            "{:<20} | {:>16} | {:>16}",
+            "Total", self.total_modules, self.total_items
+        )
+    }
+}
+
+fn percentage(part: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        part as f64 * 100.0 / total as f64
+    }
+}
@@ -1,5 +1,21 @@
+pub use self::custom_violation::CustomViolation;
+pub use self::diagnostic_style::DiagnosticStyle;
+pub(crate) use self::json_value::JsonValue;
+pub use self::layer_coverage::{LayerCoverage, LayerShare};
+pub use self::rule_explanation::RuleExplanation;
 pub use self::rule_violation_type::RuleViolationType;
+pub use self::severity::Severity;
+pub use self::violated_rule::ViolatedRule;
+pub use self::violation_fingerprint::ViolationFingerprint;
 
 /// Access rules that define the Architecture
 pub mod access_rules;
+mod custom_violation;
+mod diagnostic_style;
+mod json_value;
+mod layer_coverage;
+mod rule_explanation;
 mod rule_violation_type;
+mod severity;
+mod violated_rule;
+mod violation_fingerprint;
@@ -0,0 +1,184 @@
+use std::fmt::{Display, Formatter};
+
+use crate::analyzer::domain_values::Severity;
+
+/// What an access rule looked at when it was checked, see `Architecture::explain`
+///
+/// It tells apart rules that pass because the code is clean from rules that pass because they matched nothing.
+/// Modules are given by their fully qualified path.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct RuleExplanation {
+    rule_name: String,
+    description: String,
+    severity: Option<Severity>,
+    accessor_modules: Option<Vec<String>>,
+    accessed_modules: Option<Vec<String>>,
+    evaluated_uses: Option<usize>,
+    matching_uses: Option<usize>,
+    violations: usize,
+    exemptions: Vec<String>,
+    nested_rules: Vec<RuleExplanation>,
+}
+
+impl RuleExplanation {
+    pub fn new(rule_name: String, description: String, violations: usize) -> Self {
+        RuleExplanation {
+            rule_name,
+            description,
+            violations,
+            ..RuleExplanation::default()
+        }
+    }
+
+    /// Severity the rule is checked with, only known for rules of an `Architecture`
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
+    /// Modules whose uses the rule checks
+    pub fn with_accessor_modules(mut self, accessor_modules: Vec<String>) -> Self {
+        self.accessor_modules = Some(accessor_modules);
+        self
+    }
+
+    /// Modules the rule considers as target of these uses
+    pub fn with_accessed_modules(mut self, accessed_modules: Vec<String>) -> Self {
+        self.accessed_modules = Some(accessed_modules);
+        self
+    }
+
+    /// Number of evaluated `UseRelation`s and how many of them pointed to an accessed module
+    pub fn with_evaluated_uses(mut self, evaluated_uses: usize, matching_uses: usize) -> Self {
+        self.evaluated_uses = Some(evaluated_uses);
+        self.matching_uses = Some(matching_uses);
+        self
+    }
+
+    /// Uses or violations the rule skipped on purpose, e.g. because of `when_same_parent`
+    pub fn with_exemption(mut self, exemption: String) -> Self {
+        self.exemptions.push(exemption);
+        self
+    }
+
+    /// Explanations of the rules a combinator consists of
+    pub fn with_nested_rule(mut self, nested_rule: RuleExplanation) -> Self {
+        self.nested_rules.push(nested_rule);
+        self
+    }
+
+    pub fn rule_name(&self) -> &String {
+        &self.rule_name
+    }
+
+    pub fn description(&self) -> &String {
+        &self.description
+    }
+
+    pub fn severity(&self) -> Option<Severity> {
+        self.severity
+    }
+
+    /// `None` if the rule does not select accessor modules, e.g. custom rules
+    pub fn accessor_modules(&self) -> Option<&Vec<String>> {
+        self.accessor_modules.as_ref()
+    }
+
+    pub fn accessed_modules(&self) -> Option<&Vec<String>> {
+        self.accessed_modules.as_ref()
+    }
+
+    pub fn evaluated_uses(&self) -> Option<usize> {
+        self.evaluated_uses
+    }
+
+    pub fn matching_uses(&self) -> Option<usize> {
+        self.matching_uses
+    }
+
+    pub fn violations(&self) -> usize {
+        self.violations
+    }
+
+    pub fn exemptions(&self) -> &Vec<String> {
+        &self.exemptions
+    }
+
+    pub fn nested_rules(&self) -> &Vec<RuleExplanation> {
+        &self.nested_rules
+    }
+
+    /// Whether the rule selected modules on both sides, if it selects modules at all
+    pub fn matches_anything(&self) -> bool {
+        self.accessor_modules
+            .iter()
+            .all(|modules| !modules.is_empty())
+            && self
+                .accessed_modules
+                .iter()
+                .all(|modules| !modules.is_empty())
+            && self
+                .nested_rules
+                .iter()
+                .all(|nested_rule| nested_rule.matches_anything())
+    }
+
+    fn write_indented(&self, f: &mut Formatter<'_>, indent: usize) -> std::fmt::Result {
+        let prefix = " ".repeat(indent);
+        writeln!(f, "{}{} | {}", prefix, self.rule_name, self.description)?;
+        if let Some(severity) = self.severity {
+            writeln!(f, "{}  Severity:         {:?}", prefix, severity)?;
+        }
+        if let Some(modules) = self.accessor_modules.as_ref() {
+            write_modules(f, &prefix, "Accessor modules", modules)?;
+        }
+        if let Some(modules) = self.accessed_modules.as_ref() {
+            write_modules(f, &prefix, "Accessed modules", modules)?;
+        }
+        if let (Some(evaluated_uses), Some(matching_uses)) =
+            (self.evaluated_uses, self.matching_uses)
+        {
+            writeln!(
+                f,
+                "{}  Evaluated uses:   {} ({} to accessed modules)",
+                prefix, evaluated_uses, matching_uses
+            )?;
+        }
+        for exemption in self.exemptions.iter() {
+            writeln!(f, "{}  Exemption:        {}", prefix, exemption)?;
+        }
+        writeln!(f, "{}  Violations:       {}", prefix, self.violations)?;
+        for nested_rule in self.nested_rules.iter() {
+            nested_rule.write_indented(f, indent + 4)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for RuleExplanation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.write_indented(f, 0)
+    }
+}
+
+fn write_modules(
+    f: &mut Formatter<'_>,
+    prefix: &str,
+    title: &str,
+    modules: &[String],
+) -> std::fmt::Result {
+    if modules.is_empty() {
+        return writeln!(f, "{}  {:<17} none", prefix, format!("{}:", title));
+    }
+    writeln!(
+        f,
+        "{}  {:<17} {}",
+        prefix,
+        format!("{}:", title),
+        modules.len()
+    )?;
+    for module in modules.iter() {
+        writeln!(f, "{}    {}", prefix, module)?;
+    }
+    Ok(())
+}
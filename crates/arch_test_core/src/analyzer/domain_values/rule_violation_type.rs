@@ -4,4 +4,5 @@ pub enum RuleViolationType {
     Cycle,
     IncompleteLayerSpecification,
     LayerDoNotExist,
+    Custom,
 }
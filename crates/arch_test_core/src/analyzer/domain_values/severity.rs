@@ -0,0 +1,11 @@
+/// How violations of an access rule are treated
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum Severity {
+    /// Violations fail the check
+    #[default]
+    Deny,
+    /// Violations are reported but do not fail the check
+    Warn,
+    /// The rule is not checked at all
+    Allow,
+}
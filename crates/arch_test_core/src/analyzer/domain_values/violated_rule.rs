@@ -0,0 +1,105 @@
+use std::fmt::{Display, Formatter};
+
+use crate::analyzer::domain_values::JsonValue;
+
+/// Error codes of the built in rules, shown like rustc error codes, e.g. `error[A0006]`
+const ERROR_CODES: [(&str, &str); 14] = [
+    ("NoLayerCyclicDependencies", "A0001"),
+    ("NoModuleCyclicDependencies", "A0002"),
+    ("NoItemCyclicDependencies", "A0003"),
+    ("NoParentAccess", "A0004"),
+    ("MayOnlyAccess", "A0005"),
+    ("MayNotAccess", "A0006"),
+    ("MayOnlyBeAccessedBy", "A0007"),
+    ("MayNotBeAccessedBy", "A0008"),
+    ("ItemNaming", "A0009"),
+    ("AllOf", "A0010"),
+    ("AnyOf", "A0011"),
+    ("Not", "A0012"),
+    ("Within", "A0013"),
+    ("CompleteLayerSpecification", "A0014"),
+];
+
+/// The access rule a `RuleViolation` reports, see `AccessRule::violated_rule`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ViolatedRule {
+    name: String,
+    description: String,
+    parameters: Vec<(String, String)>,
+}
+
+impl ViolatedRule {
+    pub fn new(name: String, description: String, parameters: Vec<(String, String)>) -> Self {
+        ViolatedRule {
+            name,
+            description,
+            parameters,
+        }
+    }
+
+    /// Short name of the rule, e.g. `MayNotAccess`
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn description(&self) -> &String {
+        &self.description
+    }
+
+    /// Parameters of the rule as pairs of name and value, e.g. `("accessor", "services")`
+    pub fn parameters(&self) -> &Vec<(String, String)> {
+        &self.parameters
+    }
+
+    /// Error code of the rule, `A0000` for custom rules
+    pub fn error_code(&self) -> &'static str {
+        ERROR_CODES
+            .iter()
+            .find(|(name, _)| *name == self.name)
+            .map_or("A0000", |(_, code)| code)
+    }
+
+    /// Parameters joined like `accessor = services, accessed = [materials], when_same_parent = false`
+    pub fn parameter_list(&self) -> String {
+        self.parameters
+            .iter()
+            .map(|(name, value)| format!("{} = {}", name, value))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    pub fn parameter(&self, name: &str) -> Option<&String> {
+        self.parameters
+            .iter()
+            .find(|(parameter_name, _)| parameter_name == name)
+            .map(|(_, value)| value)
+    }
+
+    /// `{ "name": ..., "description": ..., "parameters": { "accessor": ..., ... } }`
+    pub(crate) fn to_json(&self) -> JsonValue {
+        JsonValue::object(vec![
+            ("name", JsonValue::string(&self.name)),
+            ("description", JsonValue::string(&self.description)),
+            (
+                "parameters",
+                JsonValue::Object(
+                    self.parameters
+                        .iter()
+                        .map(|(name, value)| (name.clone(), JsonValue::string(value)))
+                        .collect(),
+                ),
+            ),
+        ])
+    }
+}
+
+/// Writes the rule like `MayNotAccess(accessor = services, accessed = [materials], when_same_parent = false)`
+impl Display for ViolatedRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)?;
+        if !self.parameters.is_empty() {
+            write!(f, "({})", self.parameter_list())?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use crate::analyzer::domain_values::JsonValue;
+
+/// Identifies a violation independent of the exact location in the file
+///
+/// It consists of the name and the parameters of the violated rule and the item paths of the accessor and the accessed item,
+/// so it stays stable when unrelated code is added or moved.
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct ViolationFingerprint {
+    rule: String,
+    parameters: String,
+    accessor: String,
+    accessed: String,
+}
+
+impl ViolationFingerprint {
+    pub fn new(rule: String, parameters: String, accessor: String, accessed: String) -> Self {
+        ViolationFingerprint {
+            rule,
+            parameters,
+            accessor,
+            accessed,
+        }
+    }
+
+    pub fn rule(&self) -> &String {
+        &self.rule
+    }
+
+    /// Parameters of the rule, e.g. `accessor = services, accessed = [materials], when_same_parent = false`
+    pub fn parameters(&self) -> &String {
+        &self.parameters
+    }
+
+    pub fn accessor(&self) -> &String {
+        &self.accessor
+    }
+
+    pub fn accessed(&self) -> &String {
+        &self.accessed
+    }
+
+    /// `{ "rule": ..., "parameters": ..., "accessor": ..., "accessed": ... }`
+    pub(crate) fn to_json(&self) -> JsonValue {
+        JsonValue::object(vec![
+            ("rule", JsonValue::string(&self.rule)),
+            ("parameters", JsonValue::string(&self.parameters)),
+            ("accessor", JsonValue::string(&self.accessor)),
+            ("accessed", JsonValue::string(&self.accessed)),
+        ])
+    }
+}
@@ -1,3 +1,6 @@
+pub use self::report::{Report, REPORT_SCHEMA_VERSION};
+pub(crate) use self::rule_violation::rule_name_of;
 pub use self::rule_violation::RuleViolation;
 
+mod report;
 mod rule_violation;
@@ -0,0 +1,253 @@
+use crate::analyzer::domain_values::{DiagnosticStyle, JsonValue, Severity, ViolatedRule};
+use crate::analyzer::entities::RuleViolation;
+use crate::parser::domain_values::Suppression;
+use crate::parser::entities::ModuleNode;
+
+/// Version of the JSON schema written by `Report::to_json`, increased on incompatible changes
+pub const REPORT_SCHEMA_VERSION: usize = 1;
+
+/// Outcome of checking all access rules of an `Architecture`
+///
+/// Violations accepted by a `Suppression` and warnings are kept apart from the violations failing the check,
+/// and every suppression found in the code is listed as used or unused.
+#[derive(Debug)]
+pub struct Report {
+    rules: Vec<(ViolatedRule, Severity)>,
+    violations: Vec<RuleViolation>,
+    warnings: Vec<RuleViolation>,
+    suppressed_violations: Vec<(RuleViolation, Suppression)>,
+    suppressions: Vec<Suppression>,
+    require_reason: bool,
+}
+
+impl Report {
+    pub fn new(suppressions: Vec<Suppression>, require_reason: bool) -> Self {
+        Report {
+            rules: vec![],
+            violations: vec![],
+            warnings: vec![],
+            suppressed_violations: vec![],
+            suppressions,
+            require_reason,
+        }
+    }
+
+    /// Adds a checked rule, rules with severity `Allow` are listed as well
+    pub fn add_rule(&mut self, rule: ViolatedRule, severity: Severity) {
+        self.rules.push((rule, severity));
+    }
+
+    pub fn add_violation(&mut self, violation: RuleViolation) {
+        self.violations.push(violation);
+    }
+
+    pub fn add_warning(&mut self, violation: RuleViolation) {
+        self.warnings.push(violation);
+    }
+
+    pub fn add_suppressed_violation(&mut self, violation: RuleViolation, suppression: Suppression) {
+        self.suppressed_violations.push((violation, suppression));
+    }
+
+    /// The rules of the architecture in their order with their effective severity
+    pub fn rules(&self) -> &Vec<(ViolatedRule, Severity)> {
+        &self.rules
+    }
+
+    /// Violations of denied rules not accepted by any suppression
+    pub fn violations(&self) -> &Vec<RuleViolation> {
+        &self.violations
+    }
+
+    /// Violations of rules with severity `Warn`, they do not fail the check
+    pub fn warnings(&self) -> &Vec<RuleViolation> {
+        &self.warnings
+    }
+
+    pub fn into_violations(self) -> Vec<RuleViolation> {
+        self.violations
+    }
+
+    pub fn suppressed_violations(&self) -> &Vec<(RuleViolation, Suppression)> {
+        &self.suppressed_violations
+    }
+
+    pub fn suppressions(&self) -> &Vec<Suppression> {
+        &self.suppressions
+    }
+
+    pub fn used_suppressions(&self) -> Vec<&Suppression> {
+        self.suppressions
+            .iter()
+            .filter(|suppression| self.is_used(suppression))
+            .collect()
+    }
+
+    /// Suppressions that did not accept any violation, they can be removed
+    pub fn unused_suppressions(&self) -> Vec<&Suppression> {
+        self.suppressions
+            .iter()
+            .filter(|suppression| !self.is_used(suppression) && !self.lacks_reason(suppression))
+            .collect()
+    }
+
+    /// Suppressions ignored because a reason is required but none is given
+    pub fn suppressions_without_reason(&self) -> Vec<&Suppression> {
+        self.suppressions
+            .iter()
+            .filter(|suppression| self.lacks_reason(suppression))
+            .collect()
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    pub fn print(&self, tree: &[ModuleNode]) {
+        for violation in self.violations.iter() {
+            violation.print(tree);
+            println!();
+        }
+        for violation in self.warnings.iter() {
+            print!(
+                "{}",
+                violation.render_with(
+                    tree,
+                    DiagnosticStyle::default().with_severity(Severity::Warn)
+                )
+            );
+            println!();
+        }
+        for suppression in self.used_suppressions() {
+            println!("Used suppression      | {}", suppression);
+        }
+        for suppression in self.unused_suppressions() {
+            println!("Unused suppression    | {}", suppression);
+        }
+        for suppression in self.suppressions_without_reason() {
+            println!("Reason missing        | {}", suppression);
+        }
+    }
+
+    /// The report as indented JSON document
+    ///
+    /// The schema is versioned by `schema_version`, see `REPORT_SCHEMA_VERSION`:
+    /// ```text
+    /// {
+    ///   "schema_version": 1,
+    ///   "rules": [{ "name", "description", "parameters": { name: value }, "severity": "deny" | "warn" | "allow" }],
+    ///   "violations": [Violation],             // violations failing the check
+    ///   "warnings": [Violation],               // violations of rules with severity warn
+    ///   "suppressed_violations": [Violation],  // with an additional "suppression": Suppression
+    ///   "unused_suppressions": [Suppression]
+    /// }
+    /// Violation = {
+    ///   "rule_name": name the violation is reported with,
+    ///   "rule_index": index into "rules" or null,
+    ///   "rule": { "name", "description", "parameters" },
+    ///   "violation_type": "SingleLocation" | "Cycle" | "Custom" | "IncompleteLayerSpecification" | "LayerDoNotExist",
+    ///   "message": one line summary,
+    ///   "accessor": Location or null, "accessed": Location or null,
+    ///   "uses": [{ "accessor": Location, "accessed": Location }],  // the whole chain of a cycle
+    ///   "modules": [{ "module_path", "file" }],                      // e.g. modules without layer
+    ///   "fingerprint": { "rule", "parameters", "accessor", "accessed" }
+    /// }
+    /// Location = { "file", "line", "column", "end_line", "end_column", "item_name", "item_kind",
+    ///              "module_path", "item_path", "snippet" }  // lines and columns start with 1, the end is exclusive
+    /// Suppression = { "file", "line", "rules", "reason" }
+    /// ```
+    pub fn to_json<T: AsRef<[ModuleNode]> + ?Sized>(&self, tree: &T) -> String {
+        format!("{:#}", self.json_value(tree.as_ref()))
+    }
+
+    /// The report as `serde_json::Value`, see `to_json`
+    #[cfg(feature = "serde")]
+    pub fn to_json_value<T: AsRef<[ModuleNode]> + ?Sized>(&self, tree: &T) -> serde_json::Value {
+        self.json_value(tree.as_ref()).into()
+    }
+
+    pub(crate) fn json_value(&self, tree: &[ModuleNode]) -> JsonValue {
+        let violations_json = |violations: &Vec<RuleViolation>| {
+            JsonValue::Array(
+                violations
+                    .iter()
+                    .map(|violation| violation.json_value(tree))
+                    .collect(),
+            )
+        };
+        JsonValue::object(vec![
+            ("schema_version", JsonValue::number(REPORT_SCHEMA_VERSION)),
+            (
+                "rules",
+                JsonValue::Array(
+                    self.rules
+                        .iter()
+                        .map(|(rule, severity)| {
+                            rule.to_json().with_entry(
+                                "severity",
+                                JsonValue::String(format!("{:?}", severity).to_lowercase()),
+                            )
+                        })
+                        .collect(),
+                ),
+            ),
+            ("violations", violations_json(&self.violations)),
+            ("warnings", violations_json(&self.warnings)),
+            (
+                "suppressed_violations",
+                JsonValue::Array(
+                    self.suppressed_violations
+                        .iter()
+                        .map(|(violation, suppression)| {
+                            violation
+                                .json_value(tree)
+                                .with_entry("suppression", suppression_json(suppression))
+                        })
+                        .collect(),
+                ),
+            ),
+            (
+                "unused_suppressions",
+                JsonValue::Array(
+                    self.unused_suppressions()
+                        .into_iter()
+                        .map(suppression_json)
+                        .collect(),
+                ),
+            ),
+        ])
+    }
+
+    fn is_used(&self, suppression: &Suppression) -> bool {
+        self.suppressed_violations
+            .iter()
+            .any(|(_, used)| used == suppression)
+    }
+
+    fn lacks_reason(&self, suppression: &Suppression) -> bool {
+        self.require_reason && suppression.reason().is_none()
+    }
+}
+
+fn suppression_json(suppression: &Suppression) -> JsonValue {
+    JsonValue::object(vec![
+        ("file", JsonValue::string(suppression.file_path())),
+        ("line", JsonValue::number(suppression.line())),
+        (
+            "rules",
+            JsonValue::Array(
+                suppression
+                    .rules()
+                    .iter()
+                    .map(|rule| JsonValue::string(rule))
+                    .collect(),
+            ),
+        ),
+        (
+            "reason",
+            suppression
+                .reason()
+                .map_or(JsonValue::Null, |reason| JsonValue::string(reason)),
+        ),
+    ])
+}
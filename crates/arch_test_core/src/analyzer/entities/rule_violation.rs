@@ -1,31 +1,140 @@
-use std::fmt::Debug;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::fmt::{Debug, Display, Formatter, Write};
 
-use ra_ap_syntax::{TextRange, TextSize};
-
-use crate::analyzer::domain_values::RuleViolationType;
-use crate::parser::domain_values::UseRelation;
+use crate::analyzer::domain_values::{
+    CustomViolation, DiagnosticStyle, JsonValue, RuleViolationType, ViolatedRule,
+    ViolationFingerprint,
+};
+use crate::parser::domain_values::{ObjectUse, UseRelation};
 use crate::parser::entities::ModuleNode;
 
-#[derive(Debug)]
-pub struct RuleViolation<'r> {
+/// A violation of an access rule
+///
+/// It implements `Display` for a short summary and `std::error::Error`,
+/// `render` gives the detailed description with the offending lines.
+#[derive(Debug, Clone)]
+pub struct RuleViolation {
     violation_type: RuleViolationType,
-    access_rule: Box<dyn Debug + 'r>,
+    violated_rule: Box<ViolatedRule>,
+    /// The name the violation is reported with, followed by the names of the rules replaced by combinators
+    rule_names: Vec<String>,
     involved_object_uses: Vec<UseRelation>,
+    involved_modules: Vec<usize>,
+    rule_index: Option<usize>,
+    custom_violation: Option<Box<CustomViolation>>,
 }
 
-impl<'r> RuleViolation<'r> {
+impl RuleViolation {
     pub fn new(
         violation_type: RuleViolationType,
-        access_rule: Box<dyn Debug + 'r>,
+        violated_rule: ViolatedRule,
         involved_object_uses: Vec<UseRelation>,
     ) -> Self {
         RuleViolation {
             violation_type,
-            access_rule,
+            rule_names: vec![violated_rule.name().clone()],
+            violated_rule: Box::new(violated_rule),
             involved_object_uses,
+            involved_modules: vec![],
+            rule_index: None,
+            custom_violation: None,
+        }
+    }
+
+    /// Violation of a custom rule carrying its own message and optional location
+    pub fn custom(violated_rule: ViolatedRule, custom_violation: CustomViolation) -> Self {
+        RuleViolation {
+            violation_type: RuleViolationType::Custom,
+            rule_names: vec![violated_rule.name().clone()],
+            violated_rule: Box::new(violated_rule),
+            involved_object_uses: custom_violation
+                .use_relation()
+                .cloned()
+                .into_iter()
+                .collect(),
+            involved_modules: vec![],
+            rule_index: None,
+            custom_violation: Some(Box::new(custom_violation)),
+        }
+    }
+
+    /// Replaces the violated rule, e.g. by the combinator wrapping it
+    ///
+    /// The name of the replaced rule is kept, see `rule_names`.
+    pub fn with_violated_rule(mut self, violated_rule: ViolatedRule) -> Self {
+        let replaced_rule = std::mem::replace(&mut self.violated_rule, Box::new(violated_rule));
+        self.rule_names.insert(1, replaced_rule.name().clone());
+        self
+    }
+
+    /// Overrides the name the violation is reported with, by default the name of the violated rule
+    pub fn with_rule_name(mut self, rule_name: String) -> Self {
+        self.rule_names[0] = rule_name;
+        self
+    }
+
+    /// Adds the indices of the modules the violation is about, e.g. modules without a layer
+    pub fn with_involved_modules(mut self, involved_modules: Vec<usize>) -> Self {
+        self.involved_modules = involved_modules;
+        self
+    }
+
+    /// Remembers the position of the violated rule in the `Architecture`
+    pub fn with_rule_index(mut self, rule_index: usize) -> Self {
+        self.rule_index = Some(rule_index);
+        self
+    }
+
+    pub fn rule_index(&self) -> Option<usize> {
+        self.rule_index
+    }
+
+    pub fn rule_name(&self) -> &String {
+        &self.rule_names[0]
+    }
+
+    /// The name the violation is reported with followed by the names of the rules nested in it,
+    /// e.g. `Within` and `MayNotAccess`, suppressions and severity overrides match any of them
+    pub fn rule_names(&self) -> Vec<&String> {
+        let mut rule_names: Vec<&String> = vec![];
+        for rule_name in std::iter::once(self.rule_name())
+            .chain(std::iter::once(self.violated_rule.name()))
+            .chain(self.rule_names.iter().skip(1))
+        {
+            if !rule_names.contains(&rule_name) {
+                rule_names.push(rule_name);
+            }
         }
+        rule_names
+    }
+
+    /// Stable identification of the violation, see `ViolationFingerprint`
+    ///
+    /// Violations with several involved uses, e.g. cycles, are identified by their smallest use.
+    pub fn fingerprint(&self) -> ViolationFingerprint {
+        let custom_location = self
+            .custom_violation
+            .as_ref()
+            .filter(|custom_violation| custom_violation.use_relation().is_none())
+            .and_then(|custom_violation| custom_violation.location())
+            .map(|location| (location.item_path(), String::new()));
+        let (accessor, accessed) = self
+            .involved_object_uses
+            .iter()
+            .map(|use_relation| {
+                (
+                    use_relation.using_object().item_path(),
+                    use_relation.used_object().item_path(),
+                )
+            })
+            .chain(custom_location)
+            .min()
+            .unwrap_or_default();
+        ViolationFingerprint::new(
+            self.rule_name().clone(),
+            self.violated_rule.parameter_list(),
+            accessor,
+            accessed,
+        )
     }
 
     pub fn violation_type(&self) -> RuleViolationType {
@@ -36,103 +145,485 @@ impl<'r> RuleViolation<'r> {
         &self.involved_object_uses
     }
 
-    pub fn access_rule(&self) -> &(dyn Debug + 'r) {
-        &self.access_rule
+    pub fn involved_modules(&self) -> &Vec<usize> {
+        &self.involved_modules
+    }
+
+    pub fn violated_rule(&self) -> &ViolatedRule {
+        &self.violated_rule
+    }
+
+    pub fn custom_violation(&self) -> Option<&CustomViolation> {
+        self.custom_violation.as_deref()
     }
 
     pub fn print(&self, tree: &[ModuleNode]) {
-        match self.violation_type {
+        print!("{}", self.render(tree));
+    }
+
+    /// Detailed description of the violation as error in the style of rustc, with the offending code marked
+    ///
+    /// Accepts the `ModuleTree` as well as its nodes, e.g. `panic!("{}", violation.render(&module_tree))`.
+    pub fn render<T: AsRef<[ModuleNode]> + ?Sized>(&self, tree: &T) -> String {
+        self.render_with(tree, DiagnosticStyle::default())
+    }
+
+    /// Like `render` with the level and colour of the style, e.g. `warning` for rules with severity `Warn`
+    pub fn render_with<T: AsRef<[ModuleNode]> + ?Sized>(
+        &self,
+        tree: &T,
+        style: DiagnosticStyle,
+    ) -> String {
+        let mut rendered = String::new();
+        self.write_rendered(&mut rendered, tree.as_ref(), style)
+            .expect("Writing to a String can not fail");
+        rendered
+    }
+
+    /// The violation as JSON object, see `Report::to_json` for the schema
+    pub fn to_json<T: AsRef<[ModuleNode]> + ?Sized>(&self, tree: &T) -> String {
+        format!("{:#}", self.json_value(tree.as_ref()))
+    }
+
+    /// The violation as `serde_json::Value`, see `to_json`
+    #[cfg(feature = "serde")]
+    pub fn to_json_value<T: AsRef<[ModuleNode]> + ?Sized>(&self, tree: &T) -> serde_json::Value {
+        self.json_value(tree.as_ref()).into()
+    }
+
+    pub(crate) fn json_value(&self, tree: &[ModuleNode]) -> JsonValue {
+        let custom_location = self
+            .custom_violation
+            .as_ref()
+            .and_then(|custom_violation| custom_violation.location());
+        let accessor = custom_location
+            .or_else(|| {
+                self.involved_object_uses
+                    .first()
+                    .map(|use_relation| use_relation.using_object())
+            })
+            .map_or(JsonValue::Null, |object_use| {
+                location_json(tree, object_use)
+            });
+        let accessed = self
+            .involved_object_uses
+            .first()
+            .map_or(JsonValue::Null, |use_relation| {
+                location_json(tree, use_relation.used_object())
+            });
+        JsonValue::object(vec![
+            ("rule_name", JsonValue::string(self.rule_name())),
+            (
+                "rule_index",
+                self.rule_index.map_or(JsonValue::Null, JsonValue::number),
+            ),
+            ("rule", self.violated_rule.to_json()),
+            (
+                "violation_type",
+                JsonValue::String(format!("{:?}", self.violation_type)),
+            ),
+            ("message", JsonValue::String(self.to_string())),
+            ("accessor", accessor),
+            ("accessed", accessed),
+            (
+                "uses",
+                JsonValue::Array(
+                    self.involved_object_uses
+                        .iter()
+                        .map(|use_relation| {
+                            JsonValue::object(vec![
+                                ("accessor", location_json(tree, use_relation.using_object())),
+                                ("accessed", location_json(tree, use_relation.used_object())),
+                            ])
+                        })
+                        .collect(),
+                ),
+            ),
+            (
+                "modules",
+                JsonValue::Array(
+                    self.involved_modules
+                        .iter()
+                        .map(|node_index| {
+                            JsonValue::object(vec![
+                                (
+                                    "module_path",
+                                    JsonValue::String(
+                                        tree[*node_index].get_fully_qualified_path(tree),
+                                    ),
+                                ),
+                                ("file", JsonValue::string(tree[*node_index].file_path())),
+                            ])
+                        })
+                        .collect(),
+                ),
+            ),
+            ("fingerprint", self.fingerprint().to_json()),
+        ])
+    }
+
+    fn write_rendered(
+        &self,
+        out: &mut String,
+        tree: &[ModuleNode],
+        style: DiagnosticStyle,
+    ) -> std::fmt::Result {
+        let header = match self.violation_type {
             RuleViolationType::LayerDoNotExist => {
-                println!(
-                    "Layers specified in the rule {:?} do not match specified architecture layers.",
-                    self.access_rule
-                );
+                "Layers specified in the rule do not match specified architecture layers".to_owned()
             }
             RuleViolationType::IncompleteLayerSpecification => {
-                println!("Layer specification is incomplete!");
+                "Layer specification is incomplete".to_owned()
             }
-            RuleViolationType::SingleLocation => {
-                let using_object = self.involved_object_uses[0].using_object();
-                let used_object = self.involved_object_uses[0].used_object();
-                let (in_file_line_number, in_file_column_range, in_file_line) =
-                    find_text_range_in_file(
-                        tree[using_object.node_index()].file_path(),
-                        using_object.usable_object().text_range(),
-                    );
-                let (acc_file_line_number, acc_file_column_range, acc_file_line) =
-                    find_text_range_in_file(
-                        tree[used_object.node_index()].file_path(),
-                        used_object.usable_object().text_range(),
-                    );
-                println!("Violated rule     | {:?}", self.access_rule);
-                println!("-------------------");
-                println!(
-                    "Accessor file     | {}",
-                    tree[using_object.node_index()].file_path()
-                );
-                println!(
-                    "Object            | {:?}: {}@{:?}",
-                    using_object.usable_object().object_type(),
-                    using_object.usable_object().object_name(),
-                    using_object.usable_object().text_range()
-                );
-                println!(
-                    "Line in file      | ({}, {:?}): {}",
-                    in_file_line_number, in_file_column_range, in_file_line
-                );
-                println!("-------------------");
-                println!(
-                    "Accessed file     | {}",
-                    tree[used_object.node_index()].file_path()
-                );
-                println!("Object path       | {}", used_object.full_module_path());
-                println!(
-                    "Object            | {:?}: {}@{:?}",
-                    used_object.usable_object().object_type(),
-                    used_object.usable_object().object_name(),
-                    used_object.usable_object().text_range()
-                );
-                println!(
-                    "Line in file      | ({}, {:?}): {}",
-                    acc_file_line_number, acc_file_column_range, acc_file_line
-                );
+            RuleViolationType::Custom => self.custom_violation.as_ref().map_or_else(
+                || self.violated_rule.description().clone(),
+                |custom_violation| custom_violation.message().clone(),
+            ),
+            RuleViolationType::SingleLocation | RuleViolationType::Cycle => {
+                self.violated_rule.description().clone()
+            }
+        };
+        writeln!(
+            out,
+            "{}{}",
+            style.paint(
+                &format!("{}[{}]", style.level(), self.violated_rule.error_code()),
+                style.level_code()
+            ),
+            style.paint(&format!(": {}", header), "1")
+        )?;
+
+        let spans: Vec<SourceSpan> = self
+            .labelled_uses()
+            .into_iter()
+            .map(|(object_use, label, primary)| SourceSpan::new(tree, object_use, label, primary))
+            .collect();
+        let width = spans
+            .iter()
+            .map(|span| span.line.to_string().len())
+            .max()
+            .unwrap_or(1);
+        let gutter = style.paint(&format!("{} |", " ".repeat(width)), "1;34");
+        for (index, span) in spans.iter().enumerate() {
+            if index > 0 {
+                writeln!(out, "{}", gutter)?;
+            }
+            writeln!(
+                out,
+                "{}{} {}:{}:{}",
+                " ".repeat(width),
+                style.paint(if index == 0 { "-->" } else { ":::" }, "1;34"),
+                span.file_path,
+                span.line,
+                span.start_column
+            )?;
+            writeln!(out, "{}", gutter)?;
+            writeln!(
+                out,
+                "{} {}",
+                style.paint(&format!("{:>width$} |", span.line, width = width), "1;34"),
+                span.source_line
+            )?;
+            let marker_code = if span.primary {
+                style.level_code()
+            } else {
+                "1;34"
+            };
+            let marker = if span.primary { "^" } else { "-" };
+            writeln!(
+                out,
+                "{} {}{}",
+                gutter,
+                span.indentation(),
+                style.paint(
+                    format!("{} {}", marker.repeat(span.marker_length()), span.label).trim_end(),
+                    marker_code
+                )
+            )?;
+        }
+
+        let notes = self.notes(tree);
+        if !spans.is_empty() && !notes.is_empty() {
+            writeln!(out, "{}", gutter)?;
+        }
+        for (kind, note) in notes {
+            writeln!(
+                out,
+                "{} {} {}",
+                style.paint(&format!("{} =", " ".repeat(width)), "1;34"),
+                style.paint(&format!("{}:", kind), "1"),
+                note
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Uses shown with the offending code, each with its label and whether it is the primary span
+    fn labelled_uses(&self) -> Vec<(&ObjectUse, String, bool)> {
+        match self.violation_type {
+            RuleViolationType::SingleLocation => match self.involved_object_uses.first() {
+                Some(use_relation) => vec![
+                    (
+                        use_relation.using_object(),
+                        format!(
+                            "`{}` accesses `{}`",
+                            use_relation.using_object().full_module_path(),
+                            use_relation.used_object().item_path()
+                        ),
+                        true,
+                    ),
+                    (
+                        use_relation.used_object(),
+                        format!(
+                            "`{}` is defined here",
+                            use_relation.used_object().item_path()
+                        ),
+                        false,
+                    ),
+                ],
+                None => vec![],
+            },
+            RuleViolationType::Cycle => self
+                .involved_object_uses
+                .iter()
+                .enumerate()
+                .map(|(index, use_relation)| {
+                    (
+                        use_relation.using_object(),
+                        format!(
+                            "`{}` uses `{}`",
+                            use_relation.using_object().item_path(),
+                            use_relation.used_object().item_path()
+                        ),
+                        index == 0,
+                    )
+                })
+                .collect(),
+            RuleViolationType::Custom => {
+                let mut uses = Vec::new();
+                if let Some(custom_violation) = self.custom_violation.as_ref() {
+                    if let Some(location) = custom_violation.location() {
+                        uses.push((location, String::new(), true));
+                    }
+                    if let Some(use_relation) = custom_violation.use_relation() {
+                        uses.push((
+                            use_relation.used_object(),
+                            format!(
+                                "`{}` is accessed here",
+                                use_relation.used_object().item_path()
+                            ),
+                            false,
+                        ));
+                    }
+                }
+                uses
+            }
+            RuleViolationType::LayerDoNotExist
+            | RuleViolationType::IncompleteLayerSpecification => vec![],
+        }
+    }
+
+    /// Notes and help explaining the violated rule, written below the spans
+    fn notes(&self, tree: &[ModuleNode]) -> Vec<(&'static str, String)> {
+        let mut notes = Vec::new();
+        for node_index in self.involved_modules.iter() {
+            notes.push((
+                "note",
+                format!(
+                    "module `{}` in {} does not belong to any layer",
+                    tree[*node_index].get_fully_qualified_path(tree),
+                    tree[*node_index].file_path()
+                ),
+            ));
+        }
+        notes.push(("note", format!("violated rule `{}`", self.violated_rule)));
+        match self.violation_type {
+            RuleViolationType::Custom
+                if self.custom_violation.is_some()
+                    && self.violated_rule.description() != self.violated_rule.name() =>
+            {
+                notes.push(("note", self.violated_rule.description().clone()));
             }
             RuleViolationType::Cycle => {
-                println!("Violated rule: {:?}", self.access_rule);
-                for use_relation in self.involved_object_uses.iter() {
-                    let using_object = use_relation.using_object();
-                    println!(
-                        " | File path:    {}",
-                        tree[using_object.node_index()].file_path()
-                    );
-                    println!(
-                        " | Accessed:     {:?}: {}@{:?}",
-                        using_object.usable_object().object_type(),
-                        using_object.usable_object().object_name(),
-                        using_object.usable_object().text_range()
-                    );
-                    println!(" ⮟ ");
+                if let Some(use_relation) = self.involved_object_uses.first() {
+                    notes.push((
+                        "note",
+                        format!(
+                            "the uses lead back to `{}`",
+                            use_relation.using_object().item_path()
+                        ),
+                    ));
                 }
             }
+            _ => {}
         }
+        if !self.involved_object_uses.is_empty()
+            || self
+                .custom_violation
+                .as_ref()
+                .and_then(|custom_violation| custom_violation.location())
+                .is_some()
+        {
+            notes.push((
+                "help",
+                format!(
+                    "accept the violation with `// archtest:allow({}, reason = \"...\")`",
+                    self.rule_name()
+                ),
+            ));
+        }
+        notes
     }
 }
 
-fn find_text_range_in_file(file_path: &str, text_range: &TextRange) -> (usize, TextRange, String) {
-    let file = File::open(file_path).unwrap();
-    let reader = BufReader::new(file);
-    let mut text_conquered: u32 = 0;
-    for (line_index, line) in reader.lines().enumerate() {
-        if let Ok(line) = line {
-            if TextSize::from(text_conquered + line.len() as u32) >= text_range.end() {
-                return (
-                    line_index + 1,
-                    TextRange::new(TextSize::from(1), TextSize::from(line.len() as u32)),
-                    line,
-                );
+/// An object with the line it is written in, its columns on that line and the label shown below
+///
+/// The columns count characters and end exclusively, an object spanning several lines ends with its first line.
+struct SourceSpan {
+    file_path: String,
+    line: usize,
+    start_column: usize,
+    end_column: usize,
+    source_line: String,
+    label: String,
+    primary: bool,
+}
+
+impl SourceSpan {
+    fn new(tree: &[ModuleNode], object_use: &ObjectUse, label: String, primary: bool) -> Self {
+        let node = &tree[object_use.node_index()];
+        let (line, start_column) = object_use.usable_object().start_position();
+        let (end_line, end_column) = object_use.usable_object().end_position();
+        let source_line = node.line_index().line(line).cloned().unwrap_or_default();
+        let end_column = if end_line == line {
+            end_column
+        } else {
+            source_line.chars().count() + 1
+        };
+        SourceSpan {
+            file_path: node.file_path().clone(),
+            line,
+            start_column,
+            end_column: end_column.max(start_column),
+            source_line,
+            label,
+            primary,
+        }
+    }
+
+    fn marker_length(&self) -> usize {
+        (self.end_column - self.start_column).max(1)
+    }
+
+    /// Whitespace in front of the markers, tabs are kept so the markers line up with the source line
+    fn indentation(&self) -> String {
+        self.source_line
+            .chars()
+            .take(self.start_column.saturating_sub(1))
+            .map(|character| if character == '\t' { '\t' } else { ' ' })
+            .collect()
+    }
+}
+
+/// Short summary naming the rule and the involved items, use `render` for the offending lines
+impl Display for RuleViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.violation_type {
+            RuleViolationType::LayerDoNotExist => write!(
+                f,
+                "Layers specified in the rule {} do not match specified architecture layers",
+                self.violated_rule
+            ),
+            RuleViolationType::IncompleteLayerSpecification => write!(
+                f,
+                "Layer specification is incomplete, {} modules are not assigned to a layer",
+                self.involved_modules.len()
+            ),
+            RuleViolationType::SingleLocation => {
+                write!(
+                    f,
+                    "[{}] {}",
+                    self.rule_name(),
+                    self.violated_rule.description()
+                )?;
+                if let Some(use_relation) = self.involved_object_uses.first() {
+                    write!(
+                        f,
+                        ": {} accesses {}",
+                        use_relation.using_object().item_path(),
+                        use_relation.used_object().item_path()
+                    )?;
+                }
+                Ok(())
+            }
+            RuleViolationType::Cycle => write!(
+                f,
+                "[{}] {}: {}",
+                self.rule_name(),
+                self.violated_rule.description(),
+                self.involved_object_uses
+                    .iter()
+                    .map(|use_relation| use_relation.using_object().item_path())
+                    .collect::<Vec<String>>()
+                    .join(" -> ")
+            ),
+            RuleViolationType::Custom => {
+                write!(
+                    f,
+                    "[{}] {}",
+                    self.rule_name(),
+                    self.violated_rule.description()
+                )?;
+                if let Some(custom_violation) = self.custom_violation.as_ref() {
+                    write!(f, ": {}", custom_violation.message())?;
+                }
+                Ok(())
             }
-            text_conquered += line.len() as u32;
         }
     }
-    unreachable!()
+}
+
+impl std::error::Error for RuleViolation {}
+
+/// The leading identifier of the debug representation, e.g. `MayNotAccess`
+pub(crate) fn rule_name_of<D: Debug + ?Sized>(access_rule: &D) -> String {
+    format!("{:?}", access_rule)
+        .chars()
+        .take_while(|character| character.is_alphanumeric() || *character == '_')
+        .collect()
+}
+
+/// File, start and exclusive end position, item and source line of an object, lines and columns start with 1
+fn location_json(tree: &[ModuleNode], object_use: &ObjectUse) -> JsonValue {
+    let node = &tree[object_use.node_index()];
+    let (line, column) = object_use.usable_object().start_position();
+    let (end_line, end_column) = object_use.usable_object().end_position();
+    JsonValue::object(vec![
+        ("file", JsonValue::string(node.file_path())),
+        ("line", JsonValue::number(line)),
+        ("column", JsonValue::number(column)),
+        ("end_line", JsonValue::number(end_line)),
+        ("end_column", JsonValue::number(end_column)),
+        (
+            "item_name",
+            JsonValue::string(object_use.usable_object().object_name()),
+        ),
+        (
+            "item_kind",
+            JsonValue::String(format!("{:?}", object_use.usable_object().object_type())),
+        ),
+        (
+            "module_path",
+            JsonValue::string(object_use.full_module_path()),
+        ),
+        ("item_path", JsonValue::String(object_use.item_path())),
+        (
+            "snippet",
+            JsonValue::string(
+                node.line_index()
+                    .line(line)
+                    .map_or("", |source_line| source_line.as_str()),
+            ),
+        ),
+    ])
 }
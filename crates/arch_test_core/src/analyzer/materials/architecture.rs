@@ -1,9 +1,15 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-use crate::analyzer::domain_values::RuleViolationType;
-use crate::analyzer::entities::RuleViolation;
-use crate::analyzer::services::AccessRule;
-use crate::parser::entities::ModuleNode;
+use itertools::Itertools;
+
+use crate::analyzer::domain_values::{
+    CustomViolation, LayerCoverage, LayerShare, RuleExplanation, RuleViolationType, Severity,
+    ViolatedRule,
+};
+use crate::analyzer::entities::{Report, RuleViolation};
+use crate::analyzer::services::{find_suppression, AccessRule, CustomRule};
+use crate::parser::domain_values::GraphOptions;
+use crate::parser::entities::GraphView;
 use crate::parser::materials::ModuleTree;
 
 /// This is the central object that holds the architecture rules and executes them
@@ -24,7 +30,10 @@ use crate::parser::materials::ModuleTree;
 #[derive(Debug)]
 pub struct Architecture<'r> {
     layer_names: HashSet<String>,
-    access_rules: Vec<Box<dyn AccessRule + 'r>>,
+    access_rules: Vec<(Box<dyn AccessRule + 'r>, Severity)>,
+    severity_overrides: HashMap<String, Severity>,
+    deny_warnings: bool,
+    require_suppression_reason: bool,
 }
 
 impl<'r> Architecture<'r> {
@@ -32,20 +41,76 @@ impl<'r> Architecture<'r> {
         Architecture {
             layer_names,
             access_rules: Vec::default(),
+            severity_overrides: HashMap::default(),
+            deny_warnings: false,
+            require_suppression_reason: false,
         }
     }
 
-    pub fn with_access_rule(mut self, access_rule: impl AccessRule + 'r) -> Self {
-        self.access_rules.push(Box::new(access_rule));
+    /// Ignores suppressions in the code that do not give a reason, e.g. `// archtest:allow(NoParentAccess)`
+    pub fn with_required_suppression_reason(mut self) -> Self {
+        self.require_suppression_reason = true;
+        self
+    }
+
+    pub fn with_access_rule(self, access_rule: impl AccessRule + 'r) -> Self {
+        self.with_access_rule_severity(access_rule, Severity::Deny)
+    }
+
+    /// Adds a rule whose violations are treated according to the severity, see `Severity`
+    pub fn with_access_rule_severity(
+        mut self,
+        access_rule: impl AccessRule + 'r,
+        severity: Severity,
+    ) -> Self {
+        self.access_rules.push((Box::new(access_rule), severity));
+        self
+    }
+
+    /// Overrides the severity of every rule with the given name, e.g. `NoParentAccess`
+    ///
+    /// Also applies to the violations of rules nested in combinators, unless the combinator has an override itself.
+    pub fn with_severity_override(mut self, rule_name: &str, severity: Severity) -> Self {
+        self.severity_overrides
+            .insert(rule_name.to_owned(), severity);
+        self
+    }
+
+    /// Treats warnings like denied violations
+    pub fn with_denied_warnings(mut self) -> Self {
+        self.deny_warnings = true;
         self
     }
 
-    pub fn validate_access_rules(&'r self) -> Result<(), RuleViolation> {
-        for access_rule in self.access_rules.iter() {
+    /// Adds an ad-hoc rule defined by a closure, every returned violation fails the check
+    ///
+    /// Example:
+    /// ```ignore
+    /// let architecture = Architecture::new(hash_set!["parser".to_owned()]).with_custom_rule(
+    ///     "Modules define at most 20 items",
+    ///     |graph| {
+    ///         graph
+    ///             .modules()
+    ///             .filter(|node| graph.item_graph().items_in_module(node.index()).count() > 20)
+    ///             .map(|node| CustomViolation::new(format!("{} is too large", graph.module_path(node.index()))))
+    ///             .collect()
+    ///     },
+    /// );
+    /// ```
+    pub fn with_custom_rule(
+        self,
+        name: &str,
+        rule: impl Fn(&GraphView) -> Vec<CustomViolation> + 'r,
+    ) -> Self {
+        self.with_access_rule(CustomRule::new(name.to_owned(), rule))
+    }
+
+    pub fn validate_access_rules(&self) -> Result<(), RuleViolation> {
+        for (access_rule, _) in self.access_rules.iter() {
             if !access_rule.validate(&self.layer_names) {
                 return Err(RuleViolation::new(
                     RuleViolationType::LayerDoNotExist,
-                    Box::new(access_rule),
+                    access_rule.violated_rule(),
                     vec![],
                 ));
             }
@@ -53,31 +118,209 @@ impl<'r> Architecture<'r> {
         Ok(())
     }
 
+    /// Fails with the first violation that is not suppressed in the code
     pub fn check_access_rules(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
-        for access_rule in self.access_rules.iter() {
-            access_rule.check(&module_tree)?;
+        match self
+            .report(module_tree)
+            .into_violations()
+            .into_iter()
+            .next()
+        {
+            Some(violation) => Err(violation),
+            None => Ok(()),
         }
-        Ok(())
     }
 
+    /// Checks every access rule and separates the violations accepted by suppressions in the code
+    ///
+    /// Suppressions are written as `// archtest:allow(MayNotAccess, reason = "...")` comment
+    /// or as `#[allow_arch(MayNotAccess, reason = "...")]` attribute, see `Suppression`.
+    /// Violations of combinators are suppressed by the name of the combinator as well as of the rules nested in it.
+    pub fn report(&self, module_tree: &ModuleTree) -> Report {
+        let tree = module_tree.tree();
+        let mut report = Report::new(
+            tree.iter()
+                .flat_map(|node| node.suppressions().iter().cloned())
+                .collect(),
+            self.require_suppression_reason,
+        );
+        for (access_rule, severity) in self.access_rules.iter() {
+            report.add_rule(
+                access_rule.violated_rule(),
+                self.severity_of(access_rule.as_ref(), *severity),
+            );
+        }
+        for (violation, severity) in self.rule_violations(module_tree) {
+            match find_suppression(tree, &violation, self.require_suppression_reason) {
+                Some(suppression) => {
+                    report.add_suppressed_violation(violation, suppression.clone())
+                }
+                None if severity == Severity::Warn => report.add_warning(violation),
+                None => report.add_violation(violation),
+            }
+        }
+        report
+    }
+
+    /// Graph options highlighting every use that breaks a denied or warned rule and is not suppressed
+    ///
+    /// Example:
+    /// ```ignore
+    /// let options = architecture.graph_options(&module_tree).with_layers(architecture.layer_names().clone());
+    /// println!("{}", module_tree.to_dot_with(&options));
+    /// ```
+    pub fn graph_options(&self, module_tree: &ModuleTree) -> GraphOptions {
+        let report = self.report(module_tree);
+        report
+            .violations()
+            .iter()
+            .chain(report.warnings().iter())
+            .flat_map(|violation| violation.involved_object_uses().iter())
+            .fold(GraphOptions::default(), |options, use_relation| {
+                options.with_highlighted_use(
+                    use_relation.using_object().node_index(),
+                    use_relation.used_object().node_index(),
+                )
+            })
+    }
+
+    /// Structurizr DSL workspace with a component per layer and the uses between the layers as relationships
+    ///
+    /// Relationships with uses that break a rule are tagged `Violation`, see `ModuleGraph::to_structurizr`.
+    pub fn to_structurizr(&self, module_tree: &ModuleTree, name: &str) -> String {
+        module_tree.to_structurizr(
+            &self
+                .graph_options(module_tree)
+                .with_layers(self.layer_names.iter().cloned())
+                .with_collapsed_layers(),
+            name,
+        )
+    }
+
+    pub fn layer_names(&self) -> &HashSet<String> {
+        &self.layer_names
+    }
+
+    /// Every violation of every access rule that is not allowed, suppressions in the code are not applied
+    pub fn collect_violations(&self, module_tree: &ModuleTree) -> Vec<RuleViolation> {
+        self.rule_violations(module_tree)
+            .into_iter()
+            .map(|(violation, _)| violation)
+            .collect()
+    }
+
+    /// Explains what every access rule inspected, also of rules with severity `Allow`
+    ///
+    /// Helps to tell apart rules that pass because the code follows them from rules that match nothing,
+    /// e.g. because of a misspelled layer name.
+    pub fn explain(&self, module_tree: &ModuleTree) -> Vec<RuleExplanation> {
+        self.access_rules
+            .iter()
+            .map(|(access_rule, severity)| {
+                access_rule
+                    .explain(module_tree)
+                    .with_severity(self.severity_of(access_rule.as_ref(), *severity))
+            })
+            .collect()
+    }
+
+    /// Severity of the rule after applying the overrides
+    fn severity_of(&self, access_rule: &dyn AccessRule, severity: Severity) -> Severity {
+        self.overridden_severity(&[&access_rule.name()], severity)
+    }
+
+    /// Severity after applying the override of the first rule name that has one
+    ///
+    /// Violations of combinators are matched by the name of the combinator first and then by the names of
+    /// the rules nested in it, see `RuleViolation::rule_names`.
+    fn overridden_severity(&self, rule_names: &[&String], severity: Severity) -> Severity {
+        let severity = rule_names
+            .iter()
+            .find_map(|rule_name| self.severity_overrides.get(*rule_name))
+            .copied()
+            .unwrap_or(severity);
+        if self.deny_warnings && severity == Severity::Warn {
+            Severity::Deny
+        } else {
+            severity
+        }
+    }
+
+    fn rule_violations(&self, module_tree: &ModuleTree) -> Vec<(RuleViolation, Severity)> {
+        self.access_rules
+            .iter()
+            .enumerate()
+            .flat_map(|(rule_index, (access_rule, severity))| {
+                let rule_name = access_rule.name();
+                access_rule
+                    .violations(module_tree)
+                    .into_iter()
+                    .map(move |violation| {
+                        let violation = violation
+                            .with_rule_name(rule_name.clone())
+                            .with_rule_index(rule_index);
+                        let severity = self.overridden_severity(&violation.rule_names(), *severity);
+                        (violation, severity)
+                    })
+            })
+            .filter(|(_, severity)| *severity != Severity::Allow)
+            .collect()
+    }
+
+    /// Fails if a module neither belongs to a layer itself nor through its parent
+    ///
+    /// The violation lists every unassigned module, see `RuleViolation::involved_modules`.
     pub fn check_complete_layer_specification(
         &self,
         module_tree: &ModuleTree,
     ) -> Result<(), RuleViolation> {
-        let tree: &Vec<ModuleNode> = module_tree.tree();
-        if tree.iter().any(|node| {
-            node.parent_index().is_some()
-                && !self.layer_names.contains(node.module_name())
-                && !self
-                    .layer_names
-                    .contains(tree[node.parent_index().unwrap()].module_name())
-        }) {
-            return Err(RuleViolation::new(
-                RuleViolationType::IncompleteLayerSpecification,
-                Box::new(()),
-                vec![],
-            ));
+        let unassigned_modules = self.unassigned_modules(module_tree);
+        if unassigned_modules.is_empty() {
+            return Ok(());
         }
-        Ok(())
+        Err(RuleViolation::new(
+            RuleViolationType::IncompleteLayerSpecification,
+            ViolatedRule::new(
+                "CompleteLayerSpecification".to_owned(),
+                "Every module belongs to a layer".to_owned(),
+                vec![],
+            ),
+            vec![],
+        )
+        .with_involved_modules(unassigned_modules))
+    }
+
+    /// Indices of the modules that neither belong to a layer themselves nor through one of their parents
+    pub fn unassigned_modules(&self, module_tree: &ModuleTree) -> Vec<usize> {
+        let graph = module_tree.graph_view();
+        graph
+            .modules()
+            .filter(|node| {
+                node.parent_index().is_some()
+                    && !graph.module_in_any_layer(node.index(), self.layer_names.iter())
+            })
+            .map(|node| node.index())
+            .collect()
+    }
+
+    /// Share of the modules and items of the tree that belong to each layer
+    pub fn layer_coverage(&self, module_tree: &ModuleTree) -> LayerCoverage {
+        let graph = module_tree.graph_view();
+        let layers = self
+            .layer_names
+            .iter()
+            .sorted()
+            .map(|layer| {
+                LayerShare::new(
+                    layer.clone(),
+                    graph.modules_in_layer(layer).count(),
+                    graph
+                        .items()
+                        .filter(|item| graph.module_in_layer(item.node_index(), layer))
+                        .count(),
+                )
+            })
+            .collect();
+        LayerCoverage::new(layers, graph.modules().count(), graph.items().count())
     }
 }
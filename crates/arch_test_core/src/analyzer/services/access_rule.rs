@@ -1,28 +1,126 @@
 use std::collections::HashSet;
 use std::fmt::Debug;
 
+use itertools::Itertools;
 use velcro::hash_set;
 
 use crate::analyzer::domain_values::access_rules::{
-    MayNotAccess, MayNotBeAccessedBy, MayOnlyAccess, MayOnlyBeAccessedBy,
-    NoLayerCyclicDependencies, NoModuleCyclicDependencies, NoParentAccess,
+    AllOf, AnyOf, ItemCycleScope, ItemNaming, MayNotAccess, MayNotBeAccessedBy, MayOnlyAccess,
+    MayOnlyBeAccessedBy, NoItemCyclicDependencies, NoLayerCyclicDependencies,
+    NoModuleCyclicDependencies, NoParentAccess, Not, Within,
 };
-use crate::analyzer::domain_values::RuleViolationType;
-use crate::analyzer::entities::RuleViolation;
-use crate::analyzer::services::cyclic_dependency::{
-    contains_cyclic_dependency, contains_cyclic_dependency_on_any_level,
+use crate::analyzer::domain_values::{
+    CustomViolation, RuleExplanation, RuleViolationType, ViolatedRule,
 };
-use crate::parser::entities::ModuleNode;
+use crate::analyzer::entities::{rule_name_of, RuleViolation};
+use crate::analyzer::services::cyclic_dependency::{item_cycles, layer_cycles, module_cycles};
+use crate::parser::entities::{GraphView, ModuleNode};
 use crate::parser::materials::ModuleTree;
 use std::collections::hash_map::RandomState;
 
 pub trait AccessRule: Debug {
     fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation>;
     fn validate(&self, layer_names: &HashSet<String>) -> bool;
+
+    /// All violations of the rule, by default the one found by `check`
+    fn violations(&self, module_tree: &ModuleTree) -> Vec<RuleViolation> {
+        self.check(module_tree).err().into_iter().collect()
+    }
+
+    /// Violations of the rule when only the modules accepted by `in_scope` are considered, used by `Within`
+    ///
+    /// By default the violations whose uses and location all lie in scope. Violations without any use or location
+    /// can not be attributed to a module and are dropped, rules reporting such violations evaluate themselves
+    /// on the scope instead, e.g. `Not`. The cycle rules only search for cycles between modules in scope.
+    fn violations_within(
+        &self,
+        module_tree: &ModuleTree,
+        in_scope: &dyn Fn(usize) -> bool,
+    ) -> Vec<RuleViolation> {
+        violations_in_scope(self.violations(module_tree), in_scope)
+    }
+
+    /// Human readable description of the rule, by default its debug representation
+    fn description(&self) -> String {
+        format!("{:?}", self)
+    }
+
+    /// Short name of the rule, e.g. `MayNotAccess`
+    ///
+    /// Built-in rules return a literal, other rules default to the type name taken from their debug representation.
+    fn name(&self) -> String {
+        rule_name_of(self)
+    }
+
+    /// Parameters of the rule as pairs of name and value, by default none
+    fn parameters(&self) -> Vec<(String, String)> {
+        vec![]
+    }
+
+    /// The rule as reported by its violations
+    fn violated_rule(&self) -> ViolatedRule {
+        ViolatedRule::new(self.name(), self.description(), self.parameters())
+    }
+
+    /// What the rule inspected, by default only the number of violations
+    fn explain(&self, module_tree: &ModuleTree) -> RuleExplanation {
+        RuleExplanation::new(
+            self.name(),
+            self.description(),
+            self.violations(module_tree).len(),
+        )
+    }
+}
+
+impl<'r> AccessRule for Box<dyn AccessRule + 'r> {
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
+        (**self).check(module_tree)
+    }
+
+    fn validate(&self, layer_names: &HashSet<String>) -> bool {
+        (**self).validate(layer_names)
+    }
+
+    fn violations(&self, module_tree: &ModuleTree) -> Vec<RuleViolation> {
+        (**self).violations(module_tree)
+    }
+
+    fn violations_within(
+        &self,
+        module_tree: &ModuleTree,
+        in_scope: &dyn Fn(usize) -> bool,
+    ) -> Vec<RuleViolation> {
+        (**self).violations_within(module_tree, in_scope)
+    }
+
+    fn description(&self) -> String {
+        (**self).description()
+    }
+
+    fn name(&self) -> String {
+        (**self).name()
+    }
+
+    fn parameters(&self) -> Vec<(String, String)> {
+        (**self).parameters()
+    }
+
+    fn violated_rule(&self) -> ViolatedRule {
+        (**self).violated_rule()
+    }
+
+    fn explain(&self, module_tree: &ModuleTree) -> RuleExplanation {
+        (**self).explain(module_tree)
+    }
 }
 
 impl AccessRule for MayOnlyAccess {
     fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
+        first_violation(self.violations(module_tree))
+    }
+
+    fn violations(&self, module_tree: &ModuleTree) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
         for node in module_tree.tree().iter().filter(|node| {
             node.module_name() == self.accessor()
                 || has_parent_matching_name(
@@ -31,10 +129,10 @@ impl AccessRule for MayOnlyAccess {
                     module_tree.tree(),
                 )
         }) {
-            if let Some(use_relation) = node
+            for use_relation in node
                 .use_relations(module_tree.tree(), module_tree.possible_uses(), false)
-                .iter()
-                .find(|use_relation| {
+                .into_iter()
+                .filter(|use_relation| {
                     !self.accessed().contains(
                         module_tree.tree()[use_relation.used_object().node_index()].module_name(),
                     ) && !has_parent_matching_name(
@@ -47,14 +145,14 @@ impl AccessRule for MayOnlyAccess {
                             == node.parent_index())
                 })
             {
-                return Err(RuleViolation::new(
+                violations.push(RuleViolation::new(
                     RuleViolationType::SingleLocation,
-                    Box::new(self.clone()),
-                    vec![use_relation.clone()],
+                    self.violated_rule(),
+                    vec![use_relation],
                 ));
             }
         }
-        Ok(())
+        violations
     }
 
     fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
@@ -64,10 +162,50 @@ impl AccessRule for MayOnlyAccess {
                 .iter()
                 .all(|layer| layer_names.contains(layer))
     }
+
+    fn name(&self) -> String {
+        "MayOnlyAccess".to_owned()
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Modules in `{}` may only access {}{}",
+            self.accessor(),
+            layer_list(self.accessed()),
+            same_parent_suffix(self.when_same_parent())
+        )
+    }
+
+    fn parameters(&self) -> Vec<(String, String)> {
+        vec![
+            ("accessor".to_owned(), self.accessor().clone()),
+            ("accessed".to_owned(), layer_parameter(self.accessed())),
+            (
+                "when_same_parent".to_owned(),
+                self.when_same_parent().to_string(),
+            ),
+        ]
+    }
+
+    fn explain(&self, module_tree: &ModuleTree) -> RuleExplanation {
+        let tree = module_tree.tree();
+        explain_module_access(
+            self,
+            module_tree,
+            |accessor| in_layers(&hash_set![self.accessor().clone()], accessor, tree),
+            |_, accessed| in_layers(self.accessed(), accessed, tree),
+            self.when_same_parent(),
+        )
+    }
 }
 
 impl AccessRule for MayNotAccess {
     fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
+        first_violation(self.violations(module_tree))
+    }
+
+    fn violations(&self, module_tree: &ModuleTree) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
         for node in module_tree.tree().iter().filter(|node| {
             node.module_name() == self.accessor()
                 || has_parent_matching_name(
@@ -76,10 +214,10 @@ impl AccessRule for MayNotAccess {
                     module_tree.tree(),
                 )
         }) {
-            if let Some(use_relation) = node
+            for use_relation in node
                 .use_relations(module_tree.tree(), module_tree.possible_uses(), false)
-                .iter()
-                .find(|use_relation| {
+                .into_iter()
+                .filter(|use_relation| {
                     (self.accessed().contains(
                         module_tree.tree()[use_relation.used_object().node_index()].module_name(),
                     ) || has_parent_matching_name(
@@ -92,14 +230,14 @@ impl AccessRule for MayNotAccess {
                             == node.parent_index())
                 })
             {
-                return Err(RuleViolation::new(
+                violations.push(RuleViolation::new(
                     RuleViolationType::SingleLocation,
-                    Box::new(self.clone()),
-                    vec![use_relation.clone()],
+                    self.violated_rule(),
+                    vec![use_relation],
                 ));
             }
         }
-        Ok(())
+        violations
     }
 
     fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
@@ -109,18 +247,58 @@ impl AccessRule for MayNotAccess {
                 .iter()
                 .all(|layer| layer_names.contains(layer))
     }
+
+    fn name(&self) -> String {
+        "MayNotAccess".to_owned()
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Modules in `{}` may not access {}{}",
+            self.accessor(),
+            layer_list(self.accessed()),
+            same_parent_suffix(self.when_same_parent())
+        )
+    }
+
+    fn parameters(&self) -> Vec<(String, String)> {
+        vec![
+            ("accessor".to_owned(), self.accessor().clone()),
+            ("accessed".to_owned(), layer_parameter(self.accessed())),
+            (
+                "when_same_parent".to_owned(),
+                self.when_same_parent().to_string(),
+            ),
+        ]
+    }
+
+    fn explain(&self, module_tree: &ModuleTree) -> RuleExplanation {
+        let tree = module_tree.tree();
+        explain_module_access(
+            self,
+            module_tree,
+            |accessor| in_layers(&hash_set![self.accessor().clone()], accessor, tree),
+            |_, accessed| in_layers(self.accessed(), accessed, tree),
+            self.when_same_parent(),
+        )
+    }
 }
 
 impl AccessRule for MayOnlyBeAccessedBy {
     fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
+        first_violation(self.violations(module_tree))
+    }
+
+    fn violations(&self, module_tree: &ModuleTree) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
         for node in module_tree.tree().iter().filter(|node| {
             !self.accessors().contains(node.module_name())
                 && !has_parent_matching_name(self.accessors(), node.index(), module_tree.tree())
         }) {
-            if let Some(use_relation) = node
+            for use_relation in node
                 .use_relations(module_tree.tree(), module_tree.possible_uses(), false)
-                .iter()
-                .find(|use_relation| {
+                .into_iter()
+                .filter(|use_relation| {
                     (self.accessed()
                         == module_tree.tree()[use_relation.used_object().node_index()]
                             .module_name()
@@ -135,14 +313,14 @@ impl AccessRule for MayOnlyBeAccessedBy {
                                 == node.parent_index())
                 })
             {
-                return Err(RuleViolation::new(
+                violations.push(RuleViolation::new(
                     RuleViolationType::SingleLocation,
-                    Box::new(self.clone()),
-                    vec![use_relation.clone()],
+                    self.violated_rule(),
+                    vec![use_relation],
                 ));
             }
         }
-        Ok(())
+        violations
     }
 
     fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
@@ -152,18 +330,58 @@ impl AccessRule for MayOnlyBeAccessedBy {
                 .iter()
                 .all(|layer| layer_names.contains(layer))
     }
+
+    fn name(&self) -> String {
+        "MayOnlyBeAccessedBy".to_owned()
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Modules in `{}` may only be accessed by {}{}",
+            self.accessed(),
+            layer_list(self.accessors()),
+            same_parent_suffix(self.when_same_parent())
+        )
+    }
+
+    fn parameters(&self) -> Vec<(String, String)> {
+        vec![
+            ("accessors".to_owned(), layer_parameter(self.accessors())),
+            ("accessed".to_owned(), self.accessed().clone()),
+            (
+                "when_same_parent".to_owned(),
+                self.when_same_parent().to_string(),
+            ),
+        ]
+    }
+
+    fn explain(&self, module_tree: &ModuleTree) -> RuleExplanation {
+        let tree = module_tree.tree();
+        explain_module_access(
+            self,
+            module_tree,
+            |accessor| !in_layers(self.accessors(), accessor, tree),
+            |_, accessed| in_layers(&hash_set![self.accessed().clone()], accessed, tree),
+            self.when_same_parent(),
+        )
+    }
 }
 
 impl AccessRule for MayNotBeAccessedBy {
     fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
+        first_violation(self.violations(module_tree))
+    }
+
+    fn violations(&self, module_tree: &ModuleTree) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
         for node in module_tree.tree().iter().filter(|node| {
             self.accessors().contains(node.module_name())
                 || has_parent_matching_name(self.accessors(), node.index(), module_tree.tree())
         }) {
-            if let Some(use_relation) = node
+            for use_relation in node
                 .use_relations(module_tree.tree(), module_tree.possible_uses(), false)
-                .iter()
-                .find(|use_relation| {
+                .into_iter()
+                .filter(|use_relation| {
                     (self.accessed()
                         == module_tree.tree()[use_relation.used_object().node_index()]
                             .module_name()
@@ -178,14 +396,14 @@ impl AccessRule for MayNotBeAccessedBy {
                                 == node.parent_index())
                 })
             {
-                return Err(RuleViolation::new(
+                violations.push(RuleViolation::new(
                     RuleViolationType::SingleLocation,
-                    Box::new(self.clone()),
-                    vec![use_relation.clone()],
+                    self.violated_rule(),
+                    vec![use_relation],
                 ));
             }
         }
-        Ok(())
+        violations
     }
 
     fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
@@ -195,70 +413,749 @@ impl AccessRule for MayNotBeAccessedBy {
                 .iter()
                 .all(|layer| layer_names.contains(layer))
     }
+
+    fn name(&self) -> String {
+        "MayNotBeAccessedBy".to_owned()
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Modules in `{}` may not be accessed by {}{}",
+            self.accessed(),
+            layer_list(self.accessors()),
+            same_parent_suffix(self.when_same_parent())
+        )
+    }
+
+    fn parameters(&self) -> Vec<(String, String)> {
+        vec![
+            ("accessors".to_owned(), layer_parameter(self.accessors())),
+            ("accessed".to_owned(), self.accessed().clone()),
+            (
+                "when_same_parent".to_owned(),
+                self.when_same_parent().to_string(),
+            ),
+        ]
+    }
+
+    fn explain(&self, module_tree: &ModuleTree) -> RuleExplanation {
+        let tree = module_tree.tree();
+        explain_module_access(
+            self,
+            module_tree,
+            |accessor| in_layers(self.accessors(), accessor, tree),
+            |_, accessed| in_layers(&hash_set![self.accessed().clone()], accessed, tree),
+            self.when_same_parent(),
+        )
+    }
 }
 
 impl AccessRule for NoParentAccess {
     fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
+        first_violation(self.violations(module_tree))
+    }
+
+    fn violations(&self, module_tree: &ModuleTree) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
         for node in module_tree
             .tree()
             .iter()
             .filter(|node| node.parent_index().is_some())
         {
-            if let Some(use_relation) = node
+            for use_relation in node
                 .use_relations(module_tree.tree(), module_tree.possible_uses(), false)
-                .iter()
-                .find(|use_relation| {
+                .into_iter()
+                .filter(|use_relation| {
                     node.parent_index().is_some()
                         && node.parent_index().unwrap() == use_relation.used_object().node_index()
                 })
             {
-                return Err(RuleViolation::new(
+                violations.push(RuleViolation::new(
                     RuleViolationType::SingleLocation,
-                    Box::new(self.clone()),
-                    vec![use_relation.clone()],
+                    self.violated_rule(),
+                    vec![use_relation],
                 ));
             }
         }
-        Ok(())
+        violations
     }
 
     fn validate(&self, _layer_names: &HashSet<String, RandomState>) -> bool {
         true
     }
+
+    fn name(&self) -> String {
+        "NoParentAccess".to_owned()
+    }
+
+    fn description(&self) -> String {
+        "Modules may not access their parent module".to_owned()
+    }
+
+    fn explain(&self, module_tree: &ModuleTree) -> RuleExplanation {
+        let tree = module_tree.tree();
+        explain_module_access(
+            self,
+            module_tree,
+            |accessor| tree[accessor].parent_index().is_some(),
+            |accessor, accessed| tree[accessor].parent_index() == Some(accessed),
+            false,
+        )
+    }
 }
 
 impl AccessRule for NoModuleCyclicDependencies {
     fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
-        if let Some(involved) = contains_cyclic_dependency(module_tree) {
-            return Err(RuleViolation::new(
-                RuleViolationType::Cycle,
-                Box::new(self.clone()),
-                involved,
-            ));
-        }
-        Ok(())
+        first_violation(self.violations(module_tree))
+    }
+
+    fn violations(&self, module_tree: &ModuleTree) -> Vec<RuleViolation> {
+        self.violations_within(module_tree, &|_| true)
+    }
+
+    fn violations_within(
+        &self,
+        module_tree: &ModuleTree,
+        in_scope: &dyn Fn(usize) -> bool,
+    ) -> Vec<RuleViolation> {
+        module_cycles(module_tree, in_scope)
+            .into_iter()
+            .map(|involved| {
+                RuleViolation::new(RuleViolationType::Cycle, self.violated_rule(), involved)
+            })
+            .collect()
     }
 
     fn validate(&self, _layer_names: &HashSet<String, RandomState>) -> bool {
         true
     }
+
+    fn name(&self) -> String {
+        "NoModuleCyclicDependencies".to_owned()
+    }
+
+    fn description(&self) -> String {
+        "Modules are free of cyclic dependencies".to_owned()
+    }
+
+    fn explain(&self, module_tree: &ModuleTree) -> RuleExplanation {
+        explain_module_access(self, module_tree, |_| true, |_, _| true, false)
+    }
 }
 
 impl AccessRule for NoLayerCyclicDependencies {
     fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
-        if let Some(involved) = contains_cyclic_dependency_on_any_level(module_tree) {
-            return Err(RuleViolation::new(
-                RuleViolationType::Cycle,
-                Box::new(self.clone()),
-                involved,
-            ));
-        }
-        Ok(())
+        first_violation(self.violations(module_tree))
+    }
+
+    fn violations(&self, module_tree: &ModuleTree) -> Vec<RuleViolation> {
+        self.violations_within(module_tree, &|_| true)
+    }
+
+    fn violations_within(
+        &self,
+        module_tree: &ModuleTree,
+        in_scope: &dyn Fn(usize) -> bool,
+    ) -> Vec<RuleViolation> {
+        layer_cycles(module_tree, in_scope)
+            .into_iter()
+            .map(|involved| {
+                RuleViolation::new(RuleViolationType::Cycle, self.violated_rule(), involved)
+            })
+            .collect()
     }
 
     fn validate(&self, _layer_names: &HashSet<String, RandomState>) -> bool {
         true
     }
+
+    fn name(&self) -> String {
+        "NoLayerCyclicDependencies".to_owned()
+    }
+
+    fn description(&self) -> String {
+        "Modules are free of cyclic dependencies on every level".to_owned()
+    }
+
+    fn explain(&self, module_tree: &ModuleTree) -> RuleExplanation {
+        explain_module_access(self, module_tree, |_| true, |_, _| true, false)
+    }
+}
+
+impl AccessRule for NoItemCyclicDependencies {
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
+        first_violation(self.violations(module_tree))
+    }
+
+    fn violations(&self, module_tree: &ModuleTree) -> Vec<RuleViolation> {
+        self.violations_within(module_tree, &|_| true)
+    }
+
+    fn violations_within(
+        &self,
+        module_tree: &ModuleTree,
+        in_scope: &dyn Fn(usize) -> bool,
+    ) -> Vec<RuleViolation> {
+        let graph = module_tree.graph_view();
+        let item_graph = graph.item_graph();
+        let item_in_layer = |item: usize, layer: &String| graph.item_in_layer(item, layer);
+        let item_in_scope = |item: usize| in_scope(item_graph.items()[item].node_index());
+        let cycles = match self.scope() {
+            ItemCycleScope::Crate => item_cycles(item_graph, item_in_scope, |_| true),
+            ItemCycleScope::WithinLayer(layer) => item_cycles(
+                item_graph,
+                |item| item_in_scope(item) && item_in_layer(item, layer),
+                |_| true,
+            ),
+            ItemCycleScope::AcrossLayers(layers) => {
+                item_cycles(item_graph, item_in_scope, |component| {
+                    component
+                        .iter()
+                        .any(|item| layers.iter().any(|layer| item_in_layer(*item, layer)))
+                        && !layers
+                            .iter()
+                            .any(|layer| component.iter().all(|item| item_in_layer(*item, layer)))
+                })
+            }
+        };
+        cycles
+            .into_iter()
+            .map(|involved| {
+                RuleViolation::new(RuleViolationType::Cycle, self.violated_rule(), involved)
+            })
+            .collect()
+    }
+
+    fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
+        match self.scope() {
+            ItemCycleScope::Crate => true,
+            ItemCycleScope::WithinLayer(layer) => layer_names.contains(layer),
+            ItemCycleScope::AcrossLayers(layers) => {
+                layers.iter().all(|layer| layer_names.contains(layer))
+            }
+        }
+    }
+
+    fn name(&self) -> String {
+        "NoItemCyclicDependencies".to_owned()
+    }
+
+    fn description(&self) -> String {
+        match self.scope() {
+            ItemCycleScope::Crate => "Items are free of cyclic dependencies".to_owned(),
+            ItemCycleScope::WithinLayer(layer) => {
+                format!("Items in `{}` are free of cyclic dependencies", layer)
+            }
+            ItemCycleScope::AcrossLayers(layers) => format!(
+                "Items are free of cyclic dependencies across {}",
+                layer_list(layers)
+            ),
+        }
+    }
+
+    fn parameters(&self) -> Vec<(String, String)> {
+        let scope = match self.scope() {
+            ItemCycleScope::Crate => "Crate".to_owned(),
+            ItemCycleScope::WithinLayer(layer) => format!("WithinLayer({})", layer),
+            ItemCycleScope::AcrossLayers(layers) => {
+                format!("AcrossLayers({})", layer_parameter(layers))
+            }
+        };
+        vec![("scope".to_owned(), scope)]
+    }
+
+    fn explain(&self, module_tree: &ModuleTree) -> RuleExplanation {
+        let graph = module_tree.graph_view();
+        let in_scope = |node_index: usize| match self.scope() {
+            ItemCycleScope::Crate => true,
+            ItemCycleScope::WithinLayer(layer) => graph.module_in_layer(node_index, layer),
+            ItemCycleScope::AcrossLayers(layers) => {
+                graph.module_in_any_layer(node_index, layers.iter())
+            }
+        };
+        let item_in_scope =
+            |item_index: usize| in_scope(graph.item_graph().items()[item_index].node_index());
+        let modules = module_paths(&graph, in_scope);
+        let dependencies = graph.item_dependencies().count();
+        let matching_dependencies = graph
+            .item_dependencies()
+            .filter(|dependency| {
+                item_in_scope(dependency.using_item()) && item_in_scope(dependency.used_item())
+            })
+            .count();
+        RuleExplanation::new(
+            self.name(),
+            self.description(),
+            self.violations(module_tree).len(),
+        )
+        .with_accessor_modules(modules.clone())
+        .with_accessed_modules(modules)
+        .with_evaluated_uses(dependencies, matching_dependencies)
+    }
+}
+
+impl AccessRule for ItemNaming {
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
+        first_violation(self.violations(module_tree))
+    }
+
+    fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
+        self.layer()
+            .iter()
+            .all(|layer| layer_names.contains(*layer))
+    }
+
+    fn name(&self) -> String {
+        "ItemNaming".to_owned()
+    }
+
+    fn description(&self) -> String {
+        let items = match self.object_type() {
+            Some(object_type) => format!("{:?} items", object_type),
+            None => "Items".to_owned(),
+        };
+        match self.layer() {
+            Some(layer) => format!(
+                "{} in `{}` have to {}",
+                items,
+                layer,
+                self.convention().description()
+            ),
+            None => format!("{} have to {}", items, self.convention().description()),
+        }
+    }
+
+    fn parameters(&self) -> Vec<(String, String)> {
+        let mut parameters = vec![];
+        if let Some(object_type) = self.object_type() {
+            parameters.push(("object_type".to_owned(), format!("{:?}", object_type)));
+        }
+        if let Some(layer) = self.layer() {
+            parameters.push(("layer".to_owned(), layer.to_string()));
+        }
+        parameters.push(("convention".to_owned(), self.convention().description()));
+        parameters
+    }
+
+    fn explain(&self, module_tree: &ModuleTree) -> RuleExplanation {
+        let graph = module_tree.graph_view();
+        RuleExplanation::new(
+            self.name(),
+            self.description(),
+            self.violations(module_tree).len(),
+        )
+        .with_accessor_modules(module_paths(&graph, |node_index| {
+            self.layer()
+                .iter()
+                .all(|layer| graph.module_in_layer(node_index, layer))
+        }))
+    }
+
+    fn violations(&self, module_tree: &ModuleTree) -> Vec<RuleViolation> {
+        let graph = module_tree.graph_view();
+        graph
+            .items()
+            .enumerate()
+            .filter(|(item_index, item)| {
+                self.object_type()
+                    .iter()
+                    .all(|object_type| item.usable_object().object_type() == *object_type)
+                    && self
+                        .layer()
+                        .iter()
+                        .all(|layer| graph.item_in_layer(*item_index, layer))
+                    && !self
+                        .convention()
+                        .matches(item.usable_object().object_name())
+            })
+            .map(|(_, item)| {
+                RuleViolation::custom(
+                    self.violated_rule(),
+                    CustomViolation::new(format!(
+                        "{:?} {} does not {}",
+                        item.usable_object().object_type(),
+                        item.full_module_path(),
+                        self.convention().description()
+                    ))
+                    .with_location(item.clone()),
+                )
+            })
+            .collect()
+    }
+}
+
+impl<R: AccessRule> AccessRule for AllOf<R> {
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
+        for rule in self.rules().iter() {
+            rule.check(module_tree)?;
+        }
+        Ok(())
+    }
+
+    fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
+        self.rules().iter().all(|rule| rule.validate(layer_names))
+    }
+
+    fn name(&self) -> String {
+        "AllOf".to_owned()
+    }
+
+    fn description(&self) -> String {
+        format!("All of: {}", rule_list(self.rules()))
+    }
+
+    fn parameters(&self) -> Vec<(String, String)> {
+        vec![("rules".to_owned(), rule_parameter(self.rules()))]
+    }
+
+    fn explain(&self, module_tree: &ModuleTree) -> RuleExplanation {
+        explain_nested_rules(self, module_tree, self.rules())
+    }
+
+    fn violations(&self, module_tree: &ModuleTree) -> Vec<RuleViolation> {
+        self.rules()
+            .iter()
+            .flat_map(|rule| rule.violations(module_tree))
+            .collect()
+    }
+
+    fn violations_within(
+        &self,
+        module_tree: &ModuleTree,
+        in_scope: &dyn Fn(usize) -> bool,
+    ) -> Vec<RuleViolation> {
+        self.rules()
+            .iter()
+            .flat_map(|rule| rule.violations_within(module_tree, in_scope))
+            .collect()
+    }
+}
+
+impl<R: AccessRule> AccessRule for AnyOf<R> {
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
+        first_violation(self.violations(module_tree))
+    }
+
+    fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
+        self.rules().iter().all(|rule| rule.validate(layer_names))
+    }
+
+    fn name(&self) -> String {
+        "AnyOf".to_owned()
+    }
+
+    fn description(&self) -> String {
+        format!("Any of: {}", rule_list(self.rules()))
+    }
+
+    fn parameters(&self) -> Vec<(String, String)> {
+        vec![("rules".to_owned(), rule_parameter(self.rules()))]
+    }
+
+    fn explain(&self, module_tree: &ModuleTree) -> RuleExplanation {
+        explain_nested_rules(self, module_tree, self.rules()).with_exemption(
+            "Only the violations of the nested rule with the fewest are reported".to_owned(),
+        )
+    }
+
+    fn violations(&self, module_tree: &ModuleTree) -> Vec<RuleViolation> {
+        self.violations_within(module_tree, &|_| true)
+    }
+
+    fn violations_within(
+        &self,
+        module_tree: &ModuleTree,
+        in_scope: &dyn Fn(usize) -> bool,
+    ) -> Vec<RuleViolation> {
+        self.rules()
+            .iter()
+            .map(|rule| rule.violations_within(module_tree, in_scope))
+            .min_by_key(|violations| violations.len())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|violation| violation.with_violated_rule(self.violated_rule()))
+            .collect()
+    }
+}
+
+impl<R: AccessRule> AccessRule for Not<R> {
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
+        first_violation(self.violations(module_tree))
+    }
+
+    fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
+        self.rule().validate(layer_names)
+    }
+
+    fn name(&self) -> String {
+        "Not".to_owned()
+    }
+
+    fn description(&self) -> String {
+        format!("Not: ({})", self.rule().description())
+    }
+
+    fn parameters(&self) -> Vec<(String, String)> {
+        vec![("rule".to_owned(), self.rule().violated_rule().to_string())]
+    }
+
+    fn explain(&self, module_tree: &ModuleTree) -> RuleExplanation {
+        explain_nested_rules(self, module_tree, std::slice::from_ref(self.rule()))
+    }
+
+    fn violations(&self, module_tree: &ModuleTree) -> Vec<RuleViolation> {
+        self.violations_within(module_tree, &|_| true)
+    }
+
+    /// The nested rule is evaluated on the scope, the violation itself has no location
+    fn violations_within(
+        &self,
+        module_tree: &ModuleTree,
+        in_scope: &dyn Fn(usize) -> bool,
+    ) -> Vec<RuleViolation> {
+        if self
+            .rule()
+            .violations_within(module_tree, in_scope)
+            .is_empty()
+        {
+            vec![RuleViolation::custom(
+                self.violated_rule(),
+                CustomViolation::new(format!(
+                    "{} is satisfied, but is expected to be violated",
+                    self.rule().violated_rule()
+                )),
+            )]
+        } else {
+            vec![]
+        }
+    }
+}
+
+impl<R: AccessRule> AccessRule for Within<R> {
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
+        first_violation(self.violations(module_tree))
+    }
+
+    fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
+        self.rule().validate(layer_names)
+    }
+
+    fn name(&self) -> String {
+        "Within".to_owned()
+    }
+
+    fn description(&self) -> String {
+        format!("Within `{}`: {}", self.scope(), self.rule().description())
+    }
+
+    fn parameters(&self) -> Vec<(String, String)> {
+        vec![
+            ("scope".to_owned(), self.scope().clone()),
+            ("rule".to_owned(), self.rule().violated_rule().to_string()),
+        ]
+    }
+
+    fn explain(&self, module_tree: &ModuleTree) -> RuleExplanation {
+        let nested_rule = self.rule().explain(module_tree);
+        let violations = self.violations(module_tree).len();
+        let graph = module_tree.graph_view();
+        RuleExplanation::new(self.name(), self.description(), violations)
+            .with_accessor_modules(module_paths(&graph, |node_index| {
+                in_scope(&graph, node_index, self.scope())
+            }))
+            .with_exemption(format!(
+                "{} violations outside of `{}` were ignored",
+                nested_rule.violations().saturating_sub(violations),
+                self.scope()
+            ))
+            .with_nested_rule(nested_rule)
+    }
+
+    fn violations(&self, module_tree: &ModuleTree) -> Vec<RuleViolation> {
+        self.violations_within(module_tree, &|_| true)
+    }
+
+    fn violations_within(
+        &self,
+        module_tree: &ModuleTree,
+        outer_scope: &dyn Fn(usize) -> bool,
+    ) -> Vec<RuleViolation> {
+        let graph = module_tree.graph_view();
+        self.rule()
+            .violations_within(module_tree, &|node_index| {
+                outer_scope(node_index) && in_scope(&graph, node_index, self.scope())
+            })
+            .into_iter()
+            .map(|violation| violation.with_violated_rule(self.violated_rule()))
+            .collect()
+    }
+}
+
+/// Explains rules that check the uses of the accessor modules
+///
+/// `is_accessed` receives the accessor and the used module. With `when_same_parent`
+/// uses of accessed modules with a different parent than the accessor are skipped.
+fn explain_module_access<R: AccessRule + ?Sized>(
+    rule: &R,
+    module_tree: &ModuleTree,
+    is_accessor: impl Fn(usize) -> bool,
+    is_accessed: impl Fn(usize, usize) -> bool,
+    when_same_parent: bool,
+) -> RuleExplanation {
+    let tree = module_tree.tree();
+    let graph = module_tree.graph_view();
+    let accessors: Vec<&ModuleNode> = tree
+        .iter()
+        .filter(|node| is_accessor(node.index()))
+        .collect();
+    let mut evaluated_uses = 0;
+    let mut matching_uses = 0;
+    let mut skipped_uses = 0;
+    for node in accessors.iter() {
+        for use_relation in node.use_relations(tree, module_tree.possible_uses(), false) {
+            evaluated_uses += 1;
+            let used_index = use_relation.used_object().node_index();
+            if !is_accessed(node.index(), used_index) {
+                continue;
+            }
+            if when_same_parent && tree[used_index].parent_index() != node.parent_index() {
+                skipped_uses += 1;
+            } else {
+                matching_uses += 1;
+            }
+        }
+    }
+
+    let explanation = RuleExplanation::new(
+        rule.name(),
+        rule.description(),
+        rule.violations(module_tree).len(),
+    )
+    .with_accessor_modules(
+        accessors
+            .iter()
+            .map(|node| graph.module_path(node.index()))
+            .collect(),
+    )
+    .with_accessed_modules(module_paths(&graph, |node_index| {
+        accessors
+            .iter()
+            .any(|accessor| is_accessed(accessor.index(), node_index))
+    }))
+    .with_evaluated_uses(evaluated_uses, matching_uses);
+    if when_same_parent {
+        explanation.with_exemption(format!(
+            "{} uses of accessed modules with a different parent were skipped (when_same_parent)",
+            skipped_uses
+        ))
+    } else {
+        explanation
+    }
+}
+
+fn explain_nested_rules<R: AccessRule + ?Sized, N: AccessRule>(
+    rule: &R,
+    module_tree: &ModuleTree,
+    nested_rules: &[N],
+) -> RuleExplanation {
+    nested_rules.iter().fold(
+        RuleExplanation::new(
+            rule.name(),
+            rule.description(),
+            rule.violations(module_tree).len(),
+        ),
+        |explanation, nested_rule| explanation.with_nested_rule(nested_rule.explain(module_tree)),
+    )
+}
+
+fn module_paths(graph: &GraphView, filter: impl Fn(usize) -> bool) -> Vec<String> {
+    graph
+        .modules()
+        .filter(|node| filter(node.index()))
+        .map(|node| graph.module_path(node.index()))
+        .collect()
+}
+
+/// Whether the module or one of its parents is named like one of the layers
+fn in_layers(layers: &HashSet<String>, node_index: usize, tree: &[ModuleNode]) -> bool {
+    layers.contains(tree[node_index].module_name())
+        || has_parent_matching_name(layers, node_index, tree)
+}
+
+/// Violations with at least one use or location whose uses and location all lie in scope
+fn violations_in_scope(
+    violations: Vec<RuleViolation>,
+    in_scope: &dyn Fn(usize) -> bool,
+) -> Vec<RuleViolation> {
+    violations
+        .into_iter()
+        .filter(|violation| {
+            let mut locations = violation
+                .involved_object_uses()
+                .iter()
+                .map(|use_relation| use_relation.using_object())
+                .chain(
+                    violation
+                        .custom_violation()
+                        .and_then(|custom_violation| custom_violation.location()),
+                )
+                .peekable();
+            locations.peek().is_some()
+                && locations.all(|object_use| in_scope(object_use.node_index()))
+        })
+        .collect()
+}
+
+/// A scope is either a fully qualified module path or a name matched like a layer
+fn in_scope(graph: &GraphView, node_index: usize, scope: &str) -> bool {
+    if scope.contains("::") {
+        let module_path = graph.module_path(node_index);
+        module_path == scope || module_path.starts_with(&format!("{}::", scope))
+    } else {
+        graph.module_in_layer(node_index, scope)
+    }
+}
+
+fn layer_list(layers: &HashSet<String>) -> String {
+    layers
+        .iter()
+        .sorted()
+        .map(|layer| format!("`{}`", layer))
+        .join(", ")
+}
+
+/// Layers as parameter value, e.g. `[materials, services]`
+fn layer_parameter(layers: &HashSet<String>) -> String {
+    format!("[{}]", layers.iter().sorted().join(", "))
+}
+
+fn rule_parameter<R: AccessRule>(rules: &[R]) -> String {
+    format!(
+        "[{}]",
+        rules.iter().map(|rule| rule.violated_rule()).join(", ")
+    )
+}
+
+fn rule_list<R: AccessRule>(rules: &[R]) -> String {
+    rules
+        .iter()
+        .map(|rule| format!("({})", rule.description()))
+        .join(", ")
+}
+
+fn same_parent_suffix(when_same_parent: bool) -> &'static str {
+    if when_same_parent {
+        " when they share the same parent"
+    } else {
+        ""
+    }
+}
+
+pub(crate) fn first_violation(violations: Vec<RuleViolation>) -> Result<(), RuleViolation> {
+    match violations.into_iter().next() {
+        Some(violation) => Err(violation),
+        None => Ok(()),
+    }
 }
 
 fn has_parent_matching_name(
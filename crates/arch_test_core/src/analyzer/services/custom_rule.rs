@@ -0,0 +1,58 @@
+use std::collections::HashSet;
+use std::fmt::{Debug, Formatter};
+
+use crate::analyzer::domain_values::CustomViolation;
+use crate::analyzer::entities::RuleViolation;
+use crate::analyzer::services::access_rule::first_violation;
+use crate::analyzer::services::AccessRule;
+use crate::parser::entities::GraphView;
+use crate::parser::materials::ModuleTree;
+
+/// Access rule backed by a closure, created by `Architecture::with_custom_rule`
+pub struct CustomRule<F> {
+    name: String,
+    rule: F,
+}
+
+impl<F> CustomRule<F>
+where
+    F: Fn(&GraphView) -> Vec<CustomViolation>,
+{
+    pub fn new(name: String, rule: F) -> Self {
+        CustomRule { name, rule }
+    }
+}
+
+impl<F> Debug for CustomRule<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CustomRule").field(&self.name).finish()
+    }
+}
+
+impl<F> AccessRule for CustomRule<F>
+where
+    F: Fn(&GraphView) -> Vec<CustomViolation>,
+{
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
+        first_violation(self.violations(module_tree))
+    }
+
+    fn validate(&self, _layer_names: &HashSet<String>) -> bool {
+        true
+    }
+
+    fn description(&self) -> String {
+        self.name.clone()
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn violations(&self, module_tree: &ModuleTree) -> Vec<RuleViolation> {
+        (self.rule)(&module_tree.graph_view())
+            .into_iter()
+            .map(|violation| RuleViolation::custom(self.violated_rule(), violation))
+            .collect()
+    }
+}
@@ -1,205 +1,244 @@
-use std::collections::HashMap;
-
-use itertools::Itertools;
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use crate::parser::domain_values::UseRelation;
+use crate::parser::entities::ItemGraph;
 use crate::parser::materials::ModuleTree;
 
-pub fn contains_cyclic_dependency(module_tree: &ModuleTree) -> Option<Vec<UseRelation>> {
-    let mut visited_nodes: Vec<UseRelation> = Vec::new();
-    for (index, node) in module_tree.tree().iter().enumerate() {
-        if node
-            .use_relations(module_tree.tree(), module_tree.possible_uses(), false)
-            .iter()
-            .filter(|use_relation| use_relation.used_object().node_index() != index)
-            .dedup_by(|left, right| {
-                left.used_object().node_index() == right.used_object().node_index()
-            })
-            .any(|use_relation| {
-                visited_nodes.clear();
-                visited_nodes.push(use_relation.clone());
-                find_traverse(
-                    &mut visited_nodes,
-                    use_relation.used_object().node_index(),
-                    module_tree,
-                )
-            })
-        {
-            let last_index = visited_nodes.last().cloned().unwrap();
-            let mut result = vec![last_index.clone()];
-            for node in visited_nodes.into_iter().rev().skip(1) {
-                let using_index = node.using_object().node_index();
-                result.push(node);
-                if using_index == last_index.used_object().node_index() {
-                    break;
-                }
+/// Returns for every strongly connected component of included modules a closed chain of use relations
+/// that passes through all modules of the component.
+pub fn module_cycles(
+    module_tree: &ModuleTree,
+    include_module: impl Fn(usize) -> bool,
+) -> Vec<Vec<UseRelation>> {
+    let tree = module_tree.tree();
+    let mut graph = DependencyEdges::new(tree.len());
+    for node in tree.iter() {
+        for use_relation in node.use_relations(tree, module_tree.possible_uses(), false) {
+            let used_index = use_relation.used_object().node_index();
+            if used_index != node.index() {
+                graph.add(node.index(), used_index, use_relation);
             }
-            result.reverse();
-            return Some(result);
         }
     }
-    None
+    graph.cycles(include_module, |_| true)
 }
 
-fn find_traverse(
-    visited_nodes: &mut Vec<UseRelation>,
-    current_index: usize,
+/// Returns the cycles between the included modules of every level, uses of child modules count as uses of their parent
+/// on the level. A cycle that is made of the same uses as a cycle on a higher level is only reported once.
+pub fn layer_cycles(
     module_tree: &ModuleTree,
-) -> bool {
-    if visited_nodes
-        .iter()
-        .any(|use_relation| use_relation.using_object().node_index() == current_index)
-    {
-        return true;
-    }
-    for use_relation in module_tree.tree()[current_index]
-        .use_relations(module_tree.tree(), module_tree.possible_uses(), false)
-        .iter()
-        .filter(|use_relation| use_relation.used_object().node_index() != current_index)
-        .dedup_by(|left, right| left.used_object().node_index() == right.used_object().node_index())
-    {
-        visited_nodes.push(use_relation.clone());
-        if find_traverse(
-            visited_nodes,
-            use_relation.used_object().node_index(),
-            module_tree,
+    include_module: impl Fn(usize) -> bool,
+) -> Vec<Vec<UseRelation>> {
+    let tree = module_tree.tree();
+    let mut cycles: Vec<Vec<UseRelation>> = Vec::new();
+    let mut reported_uses: HashSet<UseRelation> = HashSet::new();
+    let mut level = 1;
+    while tree.iter().any(|node| node.level() == level) {
+        let mut node_mapping = HashMap::new();
+        for node in tree.iter().filter(|node| node.level() == level) {
+            node_mapping.insert(node.index(), node.index());
+            for included_node in node.included_nodes(tree) {
+                node_mapping.insert(included_node, node.index());
+            }
+        }
+        let mut graph = DependencyEdges::new(tree.len());
+        for node in tree.iter().filter(|node| node.level() == level) {
+            for use_relation in node.use_relations(tree, module_tree.possible_uses(), true) {
+                if let Some(used_index) = node_mapping.get(&use_relation.used_object().node_index())
+                {
+                    if *used_index != node.index() {
+                        graph.add(node.index(), *used_index, use_relation);
+                    }
+                }
+            }
+        }
+        for cycle in graph.cycles(
+            |node_index| node_mapping.contains_key(&node_index) && include_module(node_index),
+            |_| true,
         ) {
-            return true;
+            if !cycle
+                .iter()
+                .all(|use_relation| reported_uses.contains(use_relation))
+            {
+                reported_uses.extend(cycle.iter().cloned());
+                cycles.push(cycle);
+            }
         }
-        visited_nodes.pop();
+        level += 1;
     }
-    false
+    cycles
 }
 
-pub fn contains_cyclic_dependency_on_any_level(
-    module_tree: &ModuleTree,
-) -> Option<Vec<UseRelation>> {
-    let mut current_level = 1;
-    while module_tree
-        .tree()
-        .iter()
-        .any(|node| node.level() == current_level)
-    {
-        if let Some(involved) = contains_cyclic_dependency_on_level(module_tree, current_level) {
-            return Some(involved);
-        }
-        current_level += 1;
+/// Returns for every strongly connected component of included items that is accepted
+/// a closed chain of use relations that passes through all items of the component.
+pub fn item_cycles(
+    item_graph: &ItemGraph,
+    include_item: impl Fn(usize) -> bool,
+    accept_component: impl Fn(&[usize]) -> bool,
+) -> Vec<Vec<UseRelation>> {
+    let mut graph = DependencyEdges::new(item_graph.items().len());
+    for dependency in item_graph.dependencies() {
+        graph.add(
+            dependency.using_item(),
+            dependency.used_item(),
+            dependency.use_relation().clone(),
+        );
     }
-    None
+    graph.cycles(include_item, accept_component)
 }
 
-pub fn contains_cyclic_dependency_on_level(
-    module_tree: &ModuleTree,
-    level: usize,
-) -> Option<Vec<UseRelation>> {
-    let mut node_mapping = HashMap::new();
-    let mut use_relations_per_level = HashMap::new();
-    let current_tree = module_tree.tree();
-
-    current_tree
-        .iter()
-        .enumerate()
-        .filter(|(_, node)| node.level() == level)
-        .for_each(|(index, node)| {
-            let included_nodes: Vec<usize> = node.included_nodes(current_tree);
-            node_mapping.insert(index, index);
-            for node_index in included_nodes.iter() {
-                node_mapping.insert(*node_index, index);
+/// Directed graph of modules or items, keeping the first use relation of every edge
+struct DependencyEdges {
+    successors: Vec<Vec<usize>>,
+    use_relations: HashMap<(usize, usize), UseRelation>,
+}
+
+impl DependencyEdges {
+    fn new(len: usize) -> Self {
+        DependencyEdges {
+            successors: vec![Vec::new(); len],
+            use_relations: HashMap::new(),
+        }
+    }
+
+    fn add(&mut self, from: usize, to: usize, use_relation: UseRelation) {
+        if !self.use_relations.contains_key(&(from, to)) {
+            self.successors[from].push(to);
+            self.use_relations.insert((from, to), use_relation);
+        }
+    }
+
+    /// A closed chain of use relations for every strongly connected component of included nodes that is accepted
+    fn cycles(
+        &self,
+        include_node: impl Fn(usize) -> bool,
+        accept_component: impl Fn(&[usize]) -> bool,
+    ) -> Vec<Vec<UseRelation>> {
+        let mut search = ComponentSearch {
+            index: vec![None; self.successors.len()],
+            low_link: vec![0; self.successors.len()],
+            on_stack: vec![false; self.successors.len()],
+            stack: Vec::new(),
+            next_index: 0,
+            components: Vec::new(),
+        };
+        for node in 0..self.successors.len() {
+            if include_node(node) && search.index[node].is_none() {
+                search.strong_connect(node, self, &include_node);
             }
-            let level_uses: Vec<UseRelation> = node
-                .use_relations(current_tree, module_tree.possible_uses(), true)
-                .into_iter()
-                .filter(|use_relation| {
-                    !included_nodes.contains(&use_relation.used_object().node_index())
-                        && use_relation.used_object().node_index() != index
-                })
-                .collect();
-            use_relations_per_level.insert(index, level_uses);
-        });
-
-    let mut visited_nodes: Vec<UseRelation> = Vec::new();
-    for (_index, use_relations) in use_relations_per_level.iter() {
-        if use_relations
-            .iter()
-            .filter(|use_relation| {
-                node_mapping.contains_key(&use_relation.used_object().node_index())
-            })
-            .dedup_by(|left, right| {
-                node_mapping.get(&left.used_object().node_index()).unwrap()
-                    == node_mapping.get(&right.used_object().node_index()).unwrap()
-            })
-            .any(|use_relation| {
-                visited_nodes.clear();
-                visited_nodes.push(use_relation.clone());
-                find_traverse_on_level(
-                    &mut visited_nodes,
-                    *node_mapping
-                        .get(&use_relation.used_object().node_index())
-                        .unwrap(),
-                    &node_mapping,
-                    &use_relations_per_level,
-                )
-            })
-        {
-            let last_index = visited_nodes.last().cloned().unwrap();
-            let mut result = vec![last_index.clone()];
-            for node in visited_nodes.into_iter().rev().skip(1) {
-                let using_index = node_mapping
-                    .get(&node.using_object().node_index())
-                    .cloned()
-                    .unwrap();
-                result.push(node);
-                if using_index
-                    == *node_mapping
-                        .get(&last_index.used_object().node_index())
-                        .unwrap()
-                {
-                    break;
+        }
+
+        search
+            .components
+            .into_iter()
+            .filter(|component| component.len() > 1 && accept_component(component))
+            .map(|component| self.closed_chain(&component))
+            .collect()
+    }
+
+    fn closed_chain(&self, component: &[usize]) -> Vec<UseRelation> {
+        let members: HashSet<usize> = component.iter().cloned().collect();
+        let mut visited: HashSet<usize> = HashSet::new();
+        let mut result = Vec::new();
+        let mut current = component[0];
+        visited.insert(current);
+        for target in component.iter().skip(1).chain(component.iter().take(1)) {
+            if visited.contains(target) && *target != component[0] {
+                continue;
+            }
+            for (node, use_relation) in self.shortest_path(current, *target, &members) {
+                visited.insert(node);
+                result.push(use_relation);
+            }
+            current = *target;
+        }
+        result
+    }
+
+    fn shortest_path(
+        &self,
+        from: usize,
+        to: usize,
+        members: &HashSet<usize>,
+    ) -> Vec<(usize, UseRelation)> {
+        let mut predecessor: HashMap<usize, usize> = HashMap::new();
+        let mut queue = VecDeque::new();
+        queue.push_back(from);
+        while let Some(node) = queue.pop_front() {
+            if node == to && !predecessor.is_empty() {
+                break;
+            }
+            for successor in self.successors[node].iter() {
+                if members.contains(successor) && !predecessor.contains_key(successor) {
+                    predecessor.insert(*successor, node);
+                    queue.push_back(*successor);
                 }
             }
-            result.reverse();
-            return Some(result);
         }
+
+        let mut path = Vec::new();
+        let mut current = to;
+        while let Some(previous) = predecessor.get(&current) {
+            path.push((current, self.use_relations[&(*previous, current)].clone()));
+            current = *previous;
+            if current == from {
+                break;
+            }
+        }
+        path.reverse();
+        path
     }
-    None
 }
 
-fn find_traverse_on_level(
-    visited_nodes: &mut Vec<UseRelation>,
-    current_index: usize,
-    node_mapping: &HashMap<usize, usize>,
-    use_relations_per_level: &HashMap<usize, Vec<UseRelation>>,
-) -> bool {
-    if visited_nodes.iter().any(|use_relation| {
-        *node_mapping
-            .get(&use_relation.using_object().node_index())
-            .unwrap()
-            == current_index
-    }) {
-        return true;
-    }
-    for use_relation in use_relations_per_level
-        .get(&current_index)
-        .unwrap()
-        .iter()
-        .dedup_by(|left, right| {
-            node_mapping.get(&left.used_object().node_index()).unwrap()
-                == node_mapping.get(&right.used_object().node_index()).unwrap()
-        })
-    {
-        let use_relation_index = *node_mapping
-            .get(&use_relation.used_object().node_index())
-            .unwrap();
-        visited_nodes.push(use_relation.clone());
-        if find_traverse_on_level(
-            visited_nodes,
-            use_relation_index,
-            node_mapping,
-            use_relations_per_level,
-        ) {
-            return true;
+/// Tarjan's algorithm to find strongly connected components
+struct ComponentSearch {
+    index: Vec<Option<usize>>,
+    low_link: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<usize>,
+    next_index: usize,
+    components: Vec<Vec<usize>>,
+}
+
+impl ComponentSearch {
+    fn strong_connect(
+        &mut self,
+        node: usize,
+        graph: &DependencyEdges,
+        include_node: &impl Fn(usize) -> bool,
+    ) {
+        self.index[node] = Some(self.next_index);
+        self.low_link[node] = self.next_index;
+        self.next_index += 1;
+        self.stack.push(node);
+        self.on_stack[node] = true;
+
+        for successor in graph.successors[node]
+            .iter()
+            .cloned()
+            .filter(|successor| include_node(*successor))
+        {
+            if let Some(successor_index) = self.index[successor] {
+                if self.on_stack[successor] {
+                    self.low_link[node] = self.low_link[node].min(successor_index);
+                }
+            } else {
+                self.strong_connect(successor, graph, include_node);
+                self.low_link[node] = self.low_link[node].min(self.low_link[successor]);
+            }
+        }
+
+        if Some(self.low_link[node]) == self.index[node] {
+            let mut component = Vec::new();
+            while let Some(member) = self.stack.pop() {
+                self.on_stack[member] = false;
+                component.push(member);
+                if member == node {
+                    break;
+                }
+            }
+            component.sort_unstable();
+            self.components.push(component);
         }
-        visited_nodes.pop();
     }
-    false
 }
@@ -1,4 +1,10 @@
 pub use self::access_rule::AccessRule;
+pub use self::custom_rule::CustomRule;
+pub(crate) use self::suppression::find_suppression;
 
 mod access_rule;
+mod custom_rule;
 mod cyclic_dependency;
+/// Fluent builder for access rules
+pub mod rule_builder;
+mod suppression;
@@ -0,0 +1,317 @@
+use std::collections::HashSet;
+use std::fmt::{Debug, Formatter};
+
+use crate::analyzer::domain_values::access_rules::{
+    ItemNaming, MayNotAccess, MayNotBeAccessedBy, MayOnlyAccess, MayOnlyBeAccessedBy,
+    NamingConvention, NoModuleCyclicDependencies, NoParentAccess, Within,
+};
+use crate::analyzer::domain_values::RuleExplanation;
+use crate::analyzer::entities::RuleViolation;
+use crate::analyzer::services::access_rule::first_violation;
+use crate::analyzer::services::AccessRule;
+use crate::parser::domain_values::ObjectType;
+use crate::parser::materials::ModuleTree;
+
+/// Access rule built with the fluent rule builder
+///
+/// It wraps the compiled access rule and reports it with the description of the builder chain.
+pub struct ArchRule {
+    description: String,
+    rule: Box<dyn AccessRule>,
+}
+
+impl ArchRule {
+    pub fn new(description: String, rule: Box<dyn AccessRule>) -> Self {
+        ArchRule { description, rule }
+    }
+
+    pub fn rule(&self) -> &dyn AccessRule {
+        self.rule.as_ref()
+    }
+}
+
+impl Debug for ArchRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.description)
+    }
+}
+
+impl AccessRule for ArchRule {
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
+        first_violation(self.violations(module_tree))
+    }
+
+    fn validate(&self, layer_names: &HashSet<String>) -> bool {
+        self.rule.validate(layer_names)
+    }
+
+    fn violations(&self, module_tree: &ModuleTree) -> Vec<RuleViolation> {
+        self.rule
+            .violations(module_tree)
+            .into_iter()
+            .map(|violation| violation.with_violated_rule(self.violated_rule()))
+            .collect()
+    }
+
+    fn violations_within(
+        &self,
+        module_tree: &ModuleTree,
+        in_scope: &dyn Fn(usize) -> bool,
+    ) -> Vec<RuleViolation> {
+        self.rule
+            .violations_within(module_tree, in_scope)
+            .into_iter()
+            .map(|violation| violation.with_violated_rule(self.violated_rule()))
+            .collect()
+    }
+
+    fn description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn name(&self) -> String {
+        self.rule.name()
+    }
+
+    fn parameters(&self) -> Vec<(String, String)> {
+        self.rule.parameters()
+    }
+
+    fn explain(&self, module_tree: &ModuleTree) -> RuleExplanation {
+        self.rule.explain(module_tree)
+    }
+}
+
+/// Starts a rule about modules
+///
+/// Example:
+/// ```ignore
+/// let architecture = Architecture::new(hash_set!["services".to_owned(), "materials".to_owned(), "tests".to_owned()])
+///     .with_access_rule(modules().in_layer("services").should().only_be_accessed_by(["materials", "tests"]))
+///     .with_access_rule(modules().should().not_access_their_parent());
+/// ```
+pub fn modules() -> Modules {
+    Modules
+}
+
+/// Starts a rule about items, i.e. structs, enums, traits, functions and type aliases
+///
+/// Example:
+/// ```ignore
+/// let architecture = Architecture::new(hash_set!["ports".to_owned()]).with_access_rule(
+///     items().of_type(ObjectType::Trait).in_layer("ports").should().have_name_ending("Port"),
+/// );
+/// ```
+pub fn items() -> Items {
+    Items {
+        object_type: None,
+        layer: None,
+    }
+}
+
+pub struct Modules;
+
+impl Modules {
+    pub fn in_layer(self, layer: &str) -> LayerModules {
+        LayerModules {
+            layer: layer.to_owned(),
+            when_same_parent: false,
+        }
+    }
+
+    pub fn should(self) -> ModulesShould {
+        ModulesShould
+    }
+}
+
+pub struct ModulesShould;
+
+impl ModulesShould {
+    pub fn not_access_their_parent(self) -> ArchRule {
+        ArchRule::new(
+            "modules should not access their parent".to_owned(),
+            Box::new(NoParentAccess),
+        )
+    }
+
+    pub fn be_free_of_cycles(self) -> ArchRule {
+        ArchRule::new(
+            "modules should be free of cycles".to_owned(),
+            Box::new(NoModuleCyclicDependencies),
+        )
+    }
+}
+
+pub struct LayerModules {
+    layer: String,
+    when_same_parent: bool,
+}
+
+impl LayerModules {
+    /// Only considers uses between modules that share the same parent
+    pub fn when_same_parent(mut self) -> Self {
+        self.when_same_parent = true;
+        self
+    }
+
+    pub fn should(self) -> LayerModulesShould {
+        LayerModulesShould {
+            layer: self.layer,
+            when_same_parent: self.when_same_parent,
+        }
+    }
+}
+
+pub struct LayerModulesShould {
+    layer: String,
+    when_same_parent: bool,
+}
+
+impl LayerModulesShould {
+    pub fn only_access<'l>(self, layers: impl IntoIterator<Item = &'l str>) -> ArchRule {
+        let layers = owned_layers(layers);
+        ArchRule::new(
+            self.describe("only access", &layers),
+            Box::new(MayOnlyAccess::new(
+                self.layer.clone(),
+                layers.into_iter().collect(),
+                self.when_same_parent,
+            )),
+        )
+    }
+
+    pub fn not_access<'l>(self, layers: impl IntoIterator<Item = &'l str>) -> ArchRule {
+        let layers = owned_layers(layers);
+        ArchRule::new(
+            self.describe("not access", &layers),
+            Box::new(MayNotAccess::new(
+                self.layer.clone(),
+                layers.into_iter().collect(),
+                self.when_same_parent,
+            )),
+        )
+    }
+
+    pub fn only_be_accessed_by<'l>(self, layers: impl IntoIterator<Item = &'l str>) -> ArchRule {
+        let layers = owned_layers(layers);
+        ArchRule::new(
+            self.describe("only be accessed by", &layers),
+            Box::new(MayOnlyBeAccessedBy::new(
+                self.layer.clone(),
+                layers.into_iter().collect(),
+                self.when_same_parent,
+            )),
+        )
+    }
+
+    pub fn not_be_accessed_by<'l>(self, layers: impl IntoIterator<Item = &'l str>) -> ArchRule {
+        let layers = owned_layers(layers);
+        ArchRule::new(
+            self.describe("not be accessed by", &layers),
+            Box::new(MayNotBeAccessedBy::new(
+                self.layer.clone(),
+                layers.into_iter().collect(),
+                self.when_same_parent,
+            )),
+        )
+    }
+
+    /// Cycles between modules of the layer, cycles leaving the layer are not considered
+    pub fn be_free_of_cycles(self) -> ArchRule {
+        ArchRule::new(
+            format!("{} should be free of cycles", self.subject()),
+            Box::new(Within::new(self.layer, NoModuleCyclicDependencies)),
+        )
+    }
+
+    fn subject(&self) -> String {
+        if self.when_same_parent {
+            format!("modules in layer `{}` sharing the same parent", self.layer)
+        } else {
+            format!("modules in layer `{}`", self.layer)
+        }
+    }
+
+    fn describe(&self, relation: &str, layers: &[String]) -> String {
+        format!(
+            "{} should {} {}",
+            self.subject(),
+            relation,
+            layers
+                .iter()
+                .map(|layer| format!("`{}`", layer))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+pub struct Items {
+    object_type: Option<ObjectType>,
+    layer: Option<String>,
+}
+
+impl Items {
+    pub fn of_type(mut self, object_type: ObjectType) -> Self {
+        self.object_type = Some(object_type);
+        self
+    }
+
+    pub fn in_layer(mut self, layer: &str) -> Self {
+        self.layer = Some(layer.to_owned());
+        self
+    }
+
+    pub fn should(self) -> ItemsShould {
+        ItemsShould {
+            object_type: self.object_type,
+            layer: self.layer,
+        }
+    }
+}
+
+pub struct ItemsShould {
+    object_type: Option<ObjectType>,
+    layer: Option<String>,
+}
+
+impl ItemsShould {
+    pub fn have_name_starting(self, prefix: &str) -> ArchRule {
+        self.have_name(
+            format!("starting with `{}`", prefix),
+            NamingConvention::StartsWith(prefix.to_owned()),
+        )
+    }
+
+    pub fn have_name_ending(self, suffix: &str) -> ArchRule {
+        self.have_name(
+            format!("ending with `{}`", suffix),
+            NamingConvention::EndsWith(suffix.to_owned()),
+        )
+    }
+
+    pub fn have_name_containing(self, part: &str) -> ArchRule {
+        self.have_name(
+            format!("containing `{}`", part),
+            NamingConvention::Contains(part.to_owned()),
+        )
+    }
+
+    fn have_name(self, condition: String, convention: NamingConvention) -> ArchRule {
+        let mut subject = "items".to_owned();
+        if let Some(object_type) = self.object_type {
+            subject = format!("{} of type {:?}", subject, object_type);
+        }
+        if let Some(layer) = self.layer.as_ref() {
+            subject = format!("{} in layer `{}`", subject, layer);
+        }
+        ArchRule::new(
+            format!("{} should have a name {}", subject, condition),
+            Box::new(ItemNaming::new(self.object_type, self.layer, convention)),
+        )
+    }
+}
+
+fn owned_layers<'l>(layers: impl IntoIterator<Item = &'l str>) -> Vec<String> {
+    layers.into_iter().map(|layer| layer.to_owned()).collect()
+}
@@ -0,0 +1,86 @@
+use ra_ap_syntax::TextRange;
+
+use crate::analyzer::entities::RuleViolation;
+use crate::parser::domain_values::{ObjectType, ObjectUse, Suppression, SuppressionTarget};
+use crate::parser::entities::ModuleNode;
+
+/// The suppression accepting the violation, if any
+///
+/// A violation is suppressed as soon as one of its involved uses is, so a single suppression breaks a cycle.
+/// With `require_reason` suppressions without a reason are ignored.
+pub(crate) fn find_suppression<'t>(
+    tree: &'t [ModuleNode],
+    violation: &RuleViolation,
+    require_reason: bool,
+) -> Option<&'t Suppression> {
+    let custom_location = violation
+        .custom_violation()
+        .and_then(|custom_violation| custom_violation.location());
+    violation
+        .involved_object_uses()
+        .iter()
+        .map(|use_relation| use_relation.using_object())
+        .chain(custom_location)
+        .find_map(|object_use| {
+            suppression_of_object(tree, object_use, &violation.rule_names(), require_reason)
+        })
+}
+
+fn suppression_of_object<'t>(
+    tree: &'t [ModuleNode],
+    object_use: &ObjectUse,
+    rule_names: &[&String],
+    require_reason: bool,
+) -> Option<&'t Suppression> {
+    let node = &tree[object_use.node_index()];
+    let applies = |suppression: &&Suppression| {
+        rule_names
+            .iter()
+            .any(|rule_name| suppression.suppresses(rule_name))
+            && (!require_reason || suppression.reason().is_some())
+    };
+
+    let in_range = tree
+        .iter()
+        .filter(|other| other.file_path() == node.file_path())
+        .flat_map(|other| other.suppressions().iter())
+        .filter(applies)
+        .find(|suppression| match suppression.target() {
+            SuppressionTarget::Range(text_range) => {
+                text_range.contains_range(*object_use.usable_object().text_range())
+                    || imported_in_range(node, object_use, text_range)
+            }
+            _ => false,
+        });
+    if in_range.is_some() {
+        return in_range;
+    }
+
+    let mut current = Some(node.index());
+    while let Some(index) = current {
+        if let Some(suppression) = tree[index]
+            .suppressions()
+            .iter()
+            .filter(applies)
+            .find(|suppression| suppression.target() == &SuppressionTarget::Module)
+        {
+            return Some(suppression);
+        }
+        current = tree[index].parent_index();
+    }
+    None
+}
+
+/// Whether the object is used through an import written in the range, e.g. the line of the `use`
+fn imported_in_range(node: &ModuleNode, object_use: &ObjectUse, text_range: &TextRange) -> bool {
+    let usable_object = object_use.usable_object();
+    usable_object.object_type() == ObjectType::ImplicitUse
+        && node.usable_objects().iter().any(|import| {
+            (import.object_type() == ObjectType::Use
+                || import.object_type() == ObjectType::RePublish)
+                && text_range.contains_range(*import.text_range())
+                && usable_object
+                    .object_name()
+                    .starts_with(import.object_name().as_str())
+        })
+}
@@ -0,0 +1,5 @@
+use crate::domain::order::Order;
+
+pub struct Invoice {
+    order: Order,
+}
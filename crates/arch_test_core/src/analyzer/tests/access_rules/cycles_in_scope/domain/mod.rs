@@ -0,0 +1,2 @@
+mod invoice;
+mod order;
@@ -0,0 +1,5 @@
+use crate::domain::invoice::Invoice;
+
+pub struct Order {
+    invoices: Vec<Invoice>,
+}
@@ -0,0 +1,5 @@
+use crate::legacy::record::OldRecord;
+
+pub struct Archive {
+    records: Vec<OldRecord>,
+}
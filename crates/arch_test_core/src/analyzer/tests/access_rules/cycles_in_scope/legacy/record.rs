@@ -0,0 +1,5 @@
+use crate::legacy::archive::Archive;
+
+pub struct OldRecord {
+    archive: Archive,
+}
@@ -0,0 +1,7 @@
+use crate::domain::invoice::Invoice;
+use crate::legacy::archive::Archive;
+
+pub struct Order {
+    invoices: Vec<Invoice>,
+    archive: Archive,
+}
@@ -0,0 +1,5 @@
+use crate::domain::order::Order; // archtest:allow(MayNotAccess, reason = "until the archive is migrated")
+
+pub struct Archive {
+    orders: Vec<Order>,
+}
@@ -1,10 +1,18 @@
+use itertools::Itertools;
 use velcro::hash_set;
 
 use crate::analyzer::domain_values::access_rules::{
-    MayNotAccess, MayNotBeAccessedBy, MayOnlyAccess, MayOnlyBeAccessedBy,
-    NoLayerCyclicDependencies, NoModuleCyclicDependencies, NoParentAccess,
+    AllOf, AnyOf, ItemCycleScope, MayNotAccess, MayNotBeAccessedBy, MayOnlyAccess,
+    MayOnlyBeAccessedBy, NoItemCyclicDependencies, NoLayerCyclicDependencies,
+    NoModuleCyclicDependencies, NoParentAccess, Not, Within,
 };
-use crate::{Architecture, ModuleTree};
+use crate::analyzer::domain_values::{
+    CustomViolation, DiagnosticStyle, RuleViolationType, Severity, ViolationFingerprint,
+};
+use crate::analyzer::services::rule_builder::{items, modules};
+use crate::analyzer::services::AccessRule;
+use crate::parser::domain_values::ObjectType;
+use crate::{Architecture, ModuleTree, RuleViolation, REPORT_SCHEMA_VERSION};
 
 #[test]
 fn no_parent_access() {
@@ -72,6 +80,171 @@ fn cyclic_dependency_over_several_layers() {
         .print(module_tree.tree());
 }
 
+#[test]
+fn cycles_in_scope() {
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/cycles_in_scope/main.rs");
+    assert_eq!(NoModuleCyclicDependencies.violations(&module_tree).len(), 2);
+    assert_eq!(NoLayerCyclicDependencies.violations(&module_tree).len(), 2);
+    for scope in ["domain", "legacy"].iter() {
+        let access_rule = Within::new(scope.to_string(), NoModuleCyclicDependencies);
+        assert_eq!(access_rule.violations(&module_tree).len(), 1);
+        let access_rule = Within::new(scope.to_string(), NoLayerCyclicDependencies);
+        assert_eq!(access_rule.violations(&module_tree).len(), 1);
+        let access_rule = modules().in_layer(scope).should().be_free_of_cycles();
+        assert!(access_rule.check(&module_tree).is_err());
+    }
+}
+
+#[test]
+fn cycles_leaving_scope() {
+    let module_tree =
+        ModuleTree::new("src/analyzer/tests/access_rules/cycles_leaving_scope/main.rs");
+    assert_eq!(NoModuleCyclicDependencies.violations(&module_tree).len(), 1);
+    assert_eq!(NoLayerCyclicDependencies.violations(&module_tree).len(), 2);
+
+    let within_domain = |rule: Box<dyn AccessRule>| Within::new("domain".to_owned(), rule);
+    let cycle = within_domain(Box::new(NoModuleCyclicDependencies)).violations(&module_tree);
+    assert_eq!(cycle.len(), 1);
+    assert_eq!(cycle[0].involved_object_uses().len(), 2);
+    assert!(cycle[0]
+        .involved_object_uses()
+        .iter()
+        .all(
+            |use_relation| module_tree.tree()[use_relation.used_object().node_index()]
+                .file_path()
+                .contains("domain")
+        ));
+    assert_eq!(
+        within_domain(Box::new(NoLayerCyclicDependencies))
+            .violations(&module_tree)
+            .len(),
+        1
+    );
+    assert_eq!(
+        within_domain(Box::new(NoItemCyclicDependencies::new(
+            ItemCycleScope::Crate
+        )))
+        .violations(&module_tree)
+        .len(),
+        1
+    );
+    assert!(
+        within_domain(Box::new(Not::new(NoModuleCyclicDependencies)))
+            .check(&module_tree)
+            .is_ok()
+    );
+
+    let within_legacy = |rule: Box<dyn AccessRule>| Within::new("legacy".to_owned(), rule);
+    assert!(within_legacy(Box::new(NoModuleCyclicDependencies))
+        .check(&module_tree)
+        .is_ok());
+    assert!(within_legacy(Box::new(NoItemCyclicDependencies::new(
+        ItemCycleScope::Crate
+    )))
+    .check(&module_tree)
+    .is_ok());
+    assert_eq!(
+        within_legacy(Box::new(Not::new(NoModuleCyclicDependencies)))
+            .violations(&module_tree)
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn suppressions_of_nested_rules() {
+    let module_tree =
+        ModuleTree::new("src/analyzer/tests/access_rules/cycles_leaving_scope/main.rs");
+    let layers = hash_set!["domain".to_owned(), "legacy".to_owned()];
+    let rule = || {
+        Within::new(
+            "legacy".to_owned(),
+            MayNotAccess::new("legacy".to_owned(), hash_set!["domain".to_owned()], false),
+        )
+    };
+    let violations = rule().violations(&module_tree);
+    assert_eq!(violations.len(), 1);
+
+    let architecture = Architecture::new(layers.clone()).with_access_rule(rule());
+    let report = architecture.report(&module_tree);
+    assert!(report.violations().is_empty());
+    assert_eq!(report.suppressed_violations().len(), 1);
+    assert_eq!(
+        report.suppressed_violations()[0].0.rule_names(),
+        vec!["Within", "MayNotAccess"]
+    );
+
+    let architecture = Architecture::new(layers.clone())
+        .with_access_rule(AllOf::new(vec![rule()]))
+        .with_severity_override("MayNotAccess", Severity::Allow);
+    assert!(architecture.collect_violations(&module_tree).is_empty());
+    let architecture = Architecture::new(layers)
+        .with_access_rule(AllOf::new(vec![rule()]))
+        .with_severity_override("MayNotAccess", Severity::Allow)
+        .with_severity_override("AllOf", Severity::Deny);
+    assert_eq!(architecture.collect_violations(&module_tree).len(), 1);
+}
+
+#[test]
+fn no_item_cyclic_dependencies() {
+    let architecture =
+        Architecture::new(hash_set![]).with_access_rule(NoItemCyclicDependencies::default());
+    let module_tree =
+        ModuleTree::new("src/analyzer/tests/access_rules/no_item_cyclic_dependencies/main.rs");
+    assert!(architecture.check_access_rules(&module_tree).is_err());
+    architecture
+        .check_access_rules(&module_tree)
+        .err()
+        .unwrap()
+        .print(module_tree.tree());
+}
+
+#[test]
+fn no_item_cyclic_dependencies_without_item_cycle() {
+    let architecture =
+        Architecture::new(hash_set![]).with_access_rule(NoItemCyclicDependencies::default());
+    let module_tree =
+        ModuleTree::new("src/analyzer/tests/access_rules/no_module_cyclic_dependencies/main.rs");
+    assert!(architecture.check_access_rules(&module_tree).is_ok());
+}
+
+#[test]
+fn no_item_cyclic_dependencies_within_layer() {
+    let architecture = Architecture::new(hash_set!["model".to_owned(), "port".to_owned()])
+        .with_access_rule(NoItemCyclicDependencies::new(ItemCycleScope::WithinLayer(
+            "model".to_owned(),
+        )));
+    let module_tree =
+        ModuleTree::new("src/analyzer/tests/access_rules/no_item_cyclic_dependencies/main.rs");
+    assert!(architecture.validate_access_rules().is_ok());
+    assert!(architecture.check_access_rules(&module_tree).is_err());
+
+    let architecture = Architecture::new(hash_set!["model".to_owned(), "port".to_owned()])
+        .with_access_rule(NoItemCyclicDependencies::new(ItemCycleScope::WithinLayer(
+            "port".to_owned(),
+        )));
+    assert!(architecture.check_access_rules(&module_tree).is_ok());
+}
+
+#[test]
+fn no_item_cyclic_dependencies_across_layers() {
+    let layers = hash_set!["adapter".to_owned(), "model".to_owned(), "port".to_owned()];
+    let architecture = Architecture::new(layers.clone()).with_access_rule(
+        NoItemCyclicDependencies::new(ItemCycleScope::AcrossLayers(layers)),
+    );
+    let module_tree =
+        ModuleTree::new("src/analyzer/tests/access_rules/no_item_cyclic_dependencies/main.rs");
+    assert!(architecture.validate_access_rules().is_ok());
+    let violation = architecture.check_access_rules(&module_tree).err().unwrap();
+    assert_eq!(violation.involved_object_uses().len(), 2);
+    assert!(violation.involved_object_uses().iter().all(|use_relation| {
+        !module_tree.tree()[use_relation.using_object().node_index()]
+            .file_path()
+            .ends_with("model.rs")
+    }));
+    violation.print(module_tree.tree());
+}
+
 #[test]
 fn may_only_access_positive() {
     let architecture =
@@ -133,6 +306,129 @@ fn may_not_access() {
         .print(module_tree.tree());
 }
 
+#[test]
+fn rule_names() {
+    let may_not_access =
+        || MayNotAccess::new("file_1".to_owned(), hash_set!["file_2".to_owned()], false);
+    assert_eq!(may_not_access().name(), "MayNotAccess");
+    assert_eq!(NoParentAccess.name(), "NoParentAccess");
+    assert_eq!(
+        NoItemCyclicDependencies::default().name(),
+        "NoItemCyclicDependencies"
+    );
+    assert_eq!(AnyOf::new(vec![may_not_access()]).name(), "AnyOf");
+    assert_eq!(Not::new(may_not_access()).name(), "Not");
+    assert_eq!(
+        Within::new("crate::file_1".to_owned(), may_not_access()).name(),
+        "Within"
+    );
+    let boxed: Box<dyn AccessRule> = Box::new(may_not_access());
+    assert_eq!(boxed.name(), "MayNotAccess");
+    assert_eq!(
+        modules().should().not_access_their_parent().name(),
+        "NoParentAccess"
+    );
+}
+
+#[test]
+fn rule_violation_display() {
+    let architecture =
+        Architecture::new(hash_set!["file_1".to_owned(), "file_2".to_owned()]).with_access_rule(
+            MayNotAccess::new("file_1".to_owned(), hash_set!["file_2".to_owned()], false),
+        );
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    let violation = architecture.check_access_rules(&module_tree).err().unwrap();
+
+    let violated_rule = violation.violated_rule();
+    assert_eq!(violated_rule.name(), "MayNotAccess");
+    assert_eq!(
+        violated_rule.parameter("accessor"),
+        Some(&"file_1".to_owned())
+    );
+    assert_eq!(
+        violated_rule.parameter("accessed"),
+        Some(&"[file_2]".to_owned())
+    );
+
+    let summary = violation.to_string();
+    assert!(summary.starts_with("[MayNotAccess] Modules in `file_1` may not access `file_2`: "));
+    assert!(summary.contains("crate::file_2::Test2"));
+
+    let rendered = violation.render(&module_tree);
+    assert!(rendered.starts_with("error[A0006]: Modules in `file_1` may not access `file_2`\n"));
+    assert!(rendered.contains(" --> src/analyzer/tests/access_rules/may_access/file_1.rs:"));
+    assert!(rendered.contains(" ::: src/analyzer/tests/access_rules/may_access/file_2.rs:"));
+    assert!(rendered.contains("^^^ `crate::file_1` accesses `crate::file_2::Test2`"));
+    assert!(rendered.contains("----- `crate::file_2::Test2` is defined here"));
+    assert!(rendered.contains(
+        "= note: violated rule `MayNotAccess(accessor = file_1, accessed = [file_2], when_same_parent = false)`"
+    ));
+    assert!(!rendered.contains('\x1b'));
+    assert_eq!(rendered, violation.render(module_tree.tree()));
+
+    let colored = violation.render_with(&module_tree, DiagnosticStyle::new(Severity::Warn, true));
+    assert!(colored.starts_with("\x1b[1;33mwarning[A0006]\x1b[0m"));
+
+    let error: Box<dyn std::error::Error> = Box::new(violation);
+    assert_eq!(error.to_string(), summary);
+
+    let without_use = RuleViolation::new(
+        RuleViolationType::SingleLocation,
+        MayNotAccess::new("file_1".to_owned(), hash_set!["file_2".to_owned()], false)
+            .violated_rule(),
+        vec![],
+    );
+    assert_eq!(
+        without_use.to_string(),
+        "[MayNotAccess] Modules in `file_1` may not access `file_2`"
+    );
+    assert!(without_use
+        .render(&module_tree)
+        .starts_with("error[A0006]: Modules in `file_1` may not access `file_2`\n"));
+}
+
+#[test]
+fn report_to_json() {
+    let architecture = Architecture::new(hash_set!["file_1".to_owned(), "file_2".to_owned()])
+        .with_access_rule(MayNotAccess::new(
+            "file_1".to_owned(),
+            hash_set!["file_2".to_owned()],
+            false,
+        ))
+        .with_access_rule_severity(NoParentAccess, Severity::Allow);
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    let report = architecture.report(&module_tree);
+    let json: serde_json::Value = serde_json::from_str(&report.to_json(&module_tree)).unwrap();
+
+    assert_eq!(json["schema_version"], REPORT_SCHEMA_VERSION);
+    let rules = json["rules"].as_array().unwrap();
+    assert_eq!(rules.len(), 2);
+    assert_eq!(rules[1]["severity"], "allow");
+
+    let violations = json["violations"].as_array().unwrap();
+    assert_eq!(violations.len(), report.violations().len());
+    let violation = &violations[0];
+    assert_eq!(violation["violation_type"], "SingleLocation");
+    assert_eq!(violation["rule"]["parameters"]["accessor"], "file_1");
+    let accessed = &violation["accessed"];
+    assert_eq!(
+        accessed["file"],
+        "src/analyzer/tests/access_rules/may_access/file_2.rs"
+    );
+    assert_eq!(accessed["line"], 1);
+    assert_eq!(accessed["column"], 12);
+    assert_eq!(accessed["item_kind"], "Struct");
+
+    let architecture = Architecture::new(hash_set![]).with_access_rule(NoModuleCyclicDependencies);
+    let module_tree =
+        ModuleTree::new("src/analyzer/tests/access_rules/no_module_cyclic_dependencies/main.rs");
+    let json: serde_json::Value =
+        serde_json::from_str(&architecture.report(&module_tree).to_json(&module_tree)).unwrap();
+    let cycle = &json["violations"][0];
+    assert_eq!(cycle["violation_type"], "Cycle");
+    assert!(cycle["uses"].as_array().unwrap().len() > 1);
+}
+
 #[test]
 fn may_not_access_when_same_parent_positive() {
     let architecture =
@@ -256,6 +552,422 @@ fn may_not_be_accessed_by_when_same_parent_negative() {
         .print(module_tree.tree());
 }
 
+#[test]
+fn custom_rule() {
+    let architecture =
+        Architecture::new(hash_set![]).with_custom_rule("Nobody may use Test2", |graph| {
+            graph
+                .dependencies()
+                .filter(|use_relation| {
+                    use_relation.used_object().usable_object().object_name() == "Test2"
+                })
+                .map(|use_relation| {
+                    CustomViolation::new(format!(
+                        "{} uses Test2",
+                        graph.module_path(use_relation.using_object().node_index())
+                    ))
+                    .with_use_relation(use_relation)
+                })
+                .collect()
+        });
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    assert!(architecture.validate_access_rules().is_ok());
+    let violation = architecture.check_access_rules(&module_tree).err().unwrap();
+    assert!(violation
+        .custom_violation()
+        .unwrap()
+        .message()
+        .ends_with("uses Test2"));
+    assert_eq!(violation.involved_object_uses().len(), 1);
+    violation.print(module_tree.tree());
+}
+
+#[test]
+fn custom_rule_without_violations() {
+    let architecture = Architecture::new(hash_set![])
+        .with_custom_rule("Always satisfied", |_| vec![])
+        .with_custom_rule("Modules define at most one item", |graph| {
+            graph
+                .modules()
+                .filter(|node| graph.item_graph().items_in_module(node.index()).count() > 1)
+                .map(|node| {
+                    CustomViolation::new(format!(
+                        "{} is too large",
+                        graph.module_path(node.index())
+                    ))
+                })
+                .collect()
+        });
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    assert!(architecture.check_access_rules(&module_tree).is_ok());
+}
+
+#[test]
+fn all_of() {
+    let access_rule = AllOf::new(vec![
+        MayNotAccess::new("file_1".to_owned(), hash_set!["file_2".to_owned()], false),
+        MayNotAccess::new("file_3".to_owned(), hash_set!["file_2".to_owned()], false),
+    ]);
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    assert_eq!(access_rule.violations(&module_tree).len(), 2);
+    let architecture = Architecture::new(hash_set![
+        "file_1".to_owned(),
+        "file_2".to_owned(),
+        "file_3".to_owned()
+    ])
+    .with_access_rule(access_rule);
+    assert!(architecture.validate_access_rules().is_ok());
+    assert!(architecture.check_access_rules(&module_tree).is_err());
+    architecture
+        .check_access_rules(&module_tree)
+        .err()
+        .unwrap()
+        .print(module_tree.tree());
+}
+
+#[test]
+fn any_of() {
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    let access_rule = AnyOf::new(vec![
+        MayNotAccess::new("file_1".to_owned(), hash_set!["file_2".to_owned()], false),
+        MayNotAccess::new("file_3".to_owned(), hash_set!["file_2".to_owned()], false),
+    ]);
+    assert!(access_rule.check(&module_tree).is_err());
+    assert_eq!(access_rule.violations(&module_tree).len(), 1);
+
+    let access_rule = AnyOf::new(vec![
+        MayNotAccess::new("file_1".to_owned(), hash_set!["file_2".to_owned()], false),
+        MayNotAccess::new("file_1".to_owned(), hash_set!["file_3".to_owned()], false),
+    ]);
+    assert!(access_rule.check(&module_tree).is_ok());
+
+    let access_rule: AnyOf<Box<dyn AccessRule>> = AnyOf::new(vec![
+        Box::new(MayNotBeAccessedBy::new(
+            "file_2".to_owned(),
+            hash_set!["file_1".to_owned()],
+            false,
+        )),
+        Box::new(MayNotAccess::new(
+            "file_1".to_owned(),
+            hash_set!["file_2".to_owned()],
+            false,
+        )),
+    ]);
+    assert_eq!(access_rule.violations(&module_tree).len(), 1);
+    access_rule
+        .check(&module_tree)
+        .err()
+        .unwrap()
+        .print(module_tree.tree());
+}
+
+#[test]
+fn not() {
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    let architecture = Architecture::new(hash_set!["file_1".to_owned(), "file_2".to_owned()])
+        .with_access_rule(Not::new(MayNotAccess::new(
+            "file_1".to_owned(),
+            hash_set!["file_2".to_owned()],
+            false,
+        )));
+    assert!(architecture.check_access_rules(&module_tree).is_ok());
+
+    let architecture = Architecture::new(hash_set!["file_1".to_owned(), "file_2".to_owned()])
+        .with_access_rule(Not::new(MayNotAccess::new(
+            "file_2".to_owned(),
+            hash_set!["file_1".to_owned()],
+            false,
+        )));
+    assert!(architecture.check_access_rules(&module_tree).is_err());
+    architecture
+        .check_access_rules(&module_tree)
+        .err()
+        .unwrap()
+        .print(module_tree.tree());
+}
+
+#[test]
+fn within() {
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    let nobody_may_access_file_2 =
+        MayOnlyBeAccessedBy::new("file_2".to_owned(), hash_set![], false);
+    assert_eq!(nobody_may_access_file_2.violations(&module_tree).len(), 2);
+
+    let access_rule = Within::new("file_1".to_owned(), nobody_may_access_file_2.clone());
+    assert_eq!(access_rule.violations(&module_tree).len(), 1);
+    let access_rule = Within::new("crate::file_3".to_owned(), nobody_may_access_file_2.clone());
+    assert_eq!(access_rule.violations(&module_tree).len(), 1);
+    let access_rule = Within::new("file_2".to_owned(), nobody_may_access_file_2);
+    assert!(access_rule.check(&module_tree).is_ok());
+}
+
+#[test]
+fn rule_builder_modules() {
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    let access_rule = modules()
+        .in_layer("file_2")
+        .should()
+        .only_be_accessed_by(["file_1"]);
+    assert_eq!(
+        access_rule.description(),
+        "modules in layer `file_2` should only be accessed by `file_1`"
+    );
+    let architecture = Architecture::new(hash_set![
+        "file_1".to_owned(),
+        "file_2".to_owned(),
+        "file_3".to_owned()
+    ])
+    .with_access_rule(access_rule);
+    assert!(architecture.validate_access_rules().is_ok());
+    assert!(architecture.check_access_rules(&module_tree).is_err());
+    architecture
+        .check_access_rules(&module_tree)
+        .err()
+        .unwrap()
+        .print(module_tree.tree());
+
+    let architecture = Architecture::new(hash_set![])
+        .with_access_rule(
+            modules()
+                .in_layer("file_2")
+                .should()
+                .only_be_accessed_by(["file_1", "file_3"]),
+        )
+        .with_access_rule(modules().should().not_access_their_parent())
+        .with_access_rule(modules().in_layer("file_1").should().be_free_of_cycles());
+    assert!(architecture.check_access_rules(&module_tree).is_ok());
+}
+
+#[test]
+fn rule_builder_items() {
+    let module_tree =
+        ModuleTree::new("src/analyzer/tests/access_rules/no_item_cyclic_dependencies/main.rs");
+    let access_rule = items()
+        .of_type(ObjectType::Trait)
+        .in_layer("port")
+        .should()
+        .have_name_ending("Port");
+    assert_eq!(
+        access_rule.description(),
+        "items of type Trait in layer `port` should have a name ending with `Port`"
+    );
+    assert!(access_rule.check(&module_tree).is_ok());
+
+    let access_rule = items()
+        .of_type(ObjectType::Struct)
+        .should()
+        .have_name_starting("P");
+    assert_eq!(access_rule.violations(&module_tree).len(), 2);
+    access_rule
+        .check(&module_tree)
+        .err()
+        .unwrap()
+        .print(module_tree.tree());
+}
+
+#[test]
+fn collect_violations() {
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    let architecture = Architecture::new(hash_set![])
+        .with_access_rule(MayOnlyBeAccessedBy::new(
+            "file_2".to_owned(),
+            hash_set![],
+            false,
+        ))
+        .with_custom_rule("Nothing uses Test2", |graph| {
+            graph
+                .dependencies()
+                .map(|use_relation| {
+                    CustomViolation::new("Test2 is used".to_owned()).with_use_relation(use_relation)
+                })
+                .collect()
+        });
+    let violations = architecture.collect_violations(&module_tree);
+    assert_eq!(violations.len(), 4);
+
+    let fingerprints: Vec<ViolationFingerprint> = violations
+        .iter()
+        .map(|violation| violation.fingerprint())
+        .sorted()
+        .collect();
+    assert_eq!(
+        fingerprints[0],
+        ViolationFingerprint::new(
+            "MayOnlyBeAccessedBy".to_owned(),
+            "accessors = [], accessed = file_2, when_same_parent = false".to_owned(),
+            "crate::file_1".to_owned(),
+            "crate::file_2::Test2".to_owned()
+        )
+    );
+    assert_eq!(fingerprints[2].rule(), "Nothing uses Test2");
+    assert_eq!(fingerprints[3].accessor(), "crate::file_3");
+}
+
+#[test]
+fn suppressions() {
+    let architecture = Architecture::new(hash_set![]).with_access_rule(NoParentAccess);
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/suppressions/main.rs");
+    let report = architecture.report(&module_tree);
+    report.print(module_tree.tree());
+    assert_eq!(report.violations().len(), 1);
+    assert!(report.violations()[0]
+        .involved_object_uses()
+        .iter()
+        .all(
+            |use_relation| module_tree.tree()[use_relation.using_object().node_index()]
+                .module_name()
+                == "unsuppressed"
+        ));
+    assert_eq!(report.used_suppressions().len(), 3);
+    assert_eq!(report.unused_suppressions().len(), 1);
+    assert_eq!(
+        report.unused_suppressions()[0].rules(),
+        &vec!["MayNotAccess".to_owned()]
+    );
+    assert!(architecture.check_access_rules(&module_tree).is_err());
+}
+
+#[test]
+fn suppressions_with_required_reason() {
+    let architecture = Architecture::new(hash_set![])
+        .with_access_rule(NoParentAccess)
+        .with_required_suppression_reason();
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/suppressions/main.rs");
+    let report = architecture.report(&module_tree);
+    assert_eq!(report.violations().len(), 2);
+    assert_eq!(report.used_suppressions().len(), 2);
+    assert_eq!(report.suppressions_without_reason().len(), 1);
+    assert_eq!(report.suppressions_without_reason()[0].line(), 1);
+}
+
+#[test]
+fn severities() {
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/no_parent_access/main.rs");
+    let architecture =
+        Architecture::new(hash_set![]).with_access_rule_severity(NoParentAccess, Severity::Warn);
+    assert!(architecture.check_access_rules(&module_tree).is_ok());
+    let report = architecture.report(&module_tree);
+    assert!(report.is_ok());
+    assert_eq!(report.warnings().len(), 1);
+
+    let architecture = Architecture::new(hash_set![])
+        .with_access_rule_severity(NoParentAccess, Severity::Warn)
+        .with_denied_warnings();
+    assert!(architecture.check_access_rules(&module_tree).is_err());
+
+    let architecture = Architecture::new(hash_set![])
+        .with_access_rule(NoParentAccess)
+        .with_severity_override("NoParentAccess", Severity::Allow);
+    let report = architecture.report(&module_tree);
+    assert!(report.is_ok());
+    assert!(report.warnings().is_empty());
+}
+
+#[test]
+fn complete_layer_specification() {
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    let architecture = Architecture::new(hash_set![
+        "file_1".to_owned(),
+        "file_2".to_owned(),
+        "ghost".to_owned()
+    ]);
+    let violation = architecture
+        .check_complete_layer_specification(&module_tree)
+        .err()
+        .unwrap();
+    violation.print(module_tree.tree());
+    let unassigned: Vec<&String> = violation
+        .involved_modules()
+        .iter()
+        .map(|node_index| module_tree.tree()[*node_index].module_name())
+        .collect();
+    assert_eq!(unassigned, vec!["file_3"]);
+
+    let coverage = architecture.layer_coverage(&module_tree);
+    println!("{}", coverage);
+    assert_eq!(coverage.total_modules(), 4);
+    assert_eq!(coverage.layers()[0].layer(), "file_1");
+    assert_eq!(coverage.layers()[0].modules(), 1);
+    assert_eq!(coverage.module_percentage(&coverage.layers()[0]), 25.0);
+    assert_eq!(coverage.layers()[1].items(), 1);
+    assert_eq!(coverage.unmatched_layers(), vec!["ghost"]);
+
+    let architecture = Architecture::new(hash_set![
+        "file_1".to_owned(),
+        "file_2".to_owned(),
+        "file_3".to_owned()
+    ]);
+    assert!(architecture
+        .check_complete_layer_specification(&module_tree)
+        .is_ok());
+}
+
+#[test]
+fn complete_layer_specification_of_nested_modules() {
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/nested_layers/main.rs");
+    let architecture = Architecture::new(hash_set!["services".to_owned()]);
+    let unassigned: Vec<String> = architecture
+        .unassigned_modules(&module_tree)
+        .into_iter()
+        .map(|node_index| module_tree.graph_view().module_path(node_index))
+        .collect();
+    assert_eq!(unassigned, vec!["crate::utils"]);
+    assert_eq!(
+        architecture.layer_coverage(&module_tree).layers()[0].modules(),
+        3
+    );
+}
+
+#[test]
+fn explain() {
+    let architecture = Architecture::new(hash_set!["layer_1".to_owned(), "layer_2".to_owned()])
+        .with_access_rule(MayNotAccess::new(
+            "file_1".to_owned(),
+            hash_set!["file_2".to_owned()],
+            true,
+        ))
+        .with_access_rule_severity(
+            AllOf::new(vec![
+                MayOnlyAccess::new("file_4".to_owned(), hash_set![], false),
+                MayOnlyAccess::new("file_1".to_owned(), hash_set![], false),
+            ]),
+            Severity::Warn,
+        );
+    let module_tree =
+        ModuleTree::new("src/analyzer/tests/access_rules/may_access_same_parent/main.rs");
+    let explanations = architecture.explain(&module_tree);
+    for explanation in explanations.iter() {
+        println!("{}", explanation);
+    }
+
+    let same_parent = &explanations[0];
+    assert_eq!(same_parent.severity(), Some(Severity::Deny));
+    assert_eq!(
+        same_parent.accessor_modules(),
+        Some(&vec!["crate::layer_1::file_1".to_owned()])
+    );
+    assert_eq!(
+        same_parent.accessed_modules(),
+        Some(&vec!["crate::layer_2::file_2".to_owned()])
+    );
+    assert!(same_parent.evaluated_uses().unwrap() > 0);
+    assert_eq!(same_parent.matching_uses(), Some(0));
+    assert_eq!(same_parent.violations(), 0);
+    assert!(same_parent.exemptions()[0].contains("when_same_parent"));
+    assert!(same_parent.matches_anything());
+
+    let combined = &explanations[1];
+    assert_eq!(combined.severity(), Some(Severity::Warn));
+    assert_eq!(combined.nested_rules().len(), 2);
+    assert_eq!(combined.nested_rules()[0].accessor_modules(), Some(&vec![]));
+    assert!(!combined.nested_rules()[0].matches_anything());
+    assert!(combined.nested_rules()[1].violations() > 0);
+    assert_eq!(
+        combined.violations(),
+        combined.nested_rules()[1].violations()
+    );
+}
+
 #[test]
 fn myself() {
     let architecture = Architecture::new(hash_set![
@@ -310,3 +1022,21 @@ fn myself() {
     assert!(architecture.validate_access_rules().is_ok());
     assert!(architecture.check_access_rules(&module_tree).is_ok());
 }
+
+#[test]
+fn graph_options() {
+    let architecture =
+        Architecture::new(hash_set!["file_1".to_owned(), "file_2".to_owned()]).with_access_rule(
+            MayNotAccess::new("file_1".to_owned(), hash_set!["file_2".to_owned()], false),
+        );
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    let options = architecture.graph_options(&module_tree);
+    assert!(options.is_highlighted(1, 2));
+    assert!(!options.is_highlighted(3, 2));
+
+    let structurizr = architecture.to_structurizr(&module_tree, "may_access");
+    assert!(structurizr.starts_with("workspace \"may_access\" {\n    model {\n"));
+    assert!(structurizr.contains("                l0 = component \"file_1\" \"1 item\" \"Rust\"\n"));
+    assert!(structurizr.contains("        l0 -> l1 \"1 use\" \"Rust\" \"Violation\"\n"));
+    assert!(structurizr.contains("        component application \"Components\" {\n"));
+}
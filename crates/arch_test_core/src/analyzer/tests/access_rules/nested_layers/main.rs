@@ -0,0 +1,4 @@
+mod services;
+mod utils;
+
+fn main() {}
@@ -0,0 +1,9 @@
+use crate::port::Port;
+
+pub struct Adapter;
+
+impl Port for Adapter {
+    fn create() -> Adapter {
+        Adapter
+    }
+}
\ No newline at end of file
@@ -0,0 +1,3 @@
+mod adapter;
+mod model;
+mod port;
\ No newline at end of file
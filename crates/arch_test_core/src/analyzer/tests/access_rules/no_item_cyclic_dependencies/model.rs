@@ -0,0 +1,9 @@
+use std::rc::Rc;
+
+pub struct Parent {
+    child: Rc<Child>,
+}
+
+pub struct Child {
+    parent: Rc<Parent>,
+}
\ No newline at end of file
@@ -0,0 +1,5 @@
+use crate::adapter::Adapter;
+
+pub trait Port {
+    fn create() -> Adapter;
+}
\ No newline at end of file
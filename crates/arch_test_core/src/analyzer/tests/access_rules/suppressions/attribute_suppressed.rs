@@ -0,0 +1,4 @@
+#[allow_arch(NoParentAccess)]
+pub fn attribute_exec() {
+    crate::test(3);
+}
@@ -0,0 +1,5 @@
+use crate::test; // archtest:allow(NoParentAccess, reason = "needs the helper")
+
+pub fn line_exec() {
+    test(1);
+}
@@ -0,0 +1,9 @@
+mod line_suppressed;
+// archtest:allow(NoParentAccess, reason = "migrated later")
+mod module_suppressed;
+mod attribute_suppressed;
+mod unsuppressed;
+
+pub fn test(sth: u32) {
+
+}
@@ -0,0 +1,5 @@
+use crate::test;
+
+pub fn module_exec() {
+    test(2);
+}
@@ -0,0 +1,5 @@
+use crate::test; // archtest:allow(MayNotAccess, reason = "wrong rule")
+
+pub fn unsuppressed_exec() {
+    test(4);
+}
@@ -2,10 +2,13 @@
 //! ArchTest is a rule based architecture testing tool. It applies static analyses on the specified rust project to extract use relationships.
 //!
 //! ## Features
-//! * Detect cyclic dependencies level wise or module wise
+//! * Detect cyclic dependencies level wise, module wise or item wise
 //! * Prohibit parent access
 //! * Define layer relationships like `MayNotAccess`, `MayOnlyAccess`, `MyNotBeAccessedBy`, `MayOnlyBeAccessedBy` etc.
+//! * Combine rules with `AllOf`, `AnyOf`, `Not` and `Within`
+//! * Describe rules fluently, e.g. `modules().in_layer("services").should().only_be_accessed_by(["materials"])`
 //! * For more access rules consult `access_rules`.
+//! * Save and load the parsed `ModuleTree` with the optional feature `serde`
 //!
 //! ## Install
 //! ```toml
@@ -33,6 +36,49 @@
 //! If you are interested in the failure you can pretty print it like this:
 //! ```ignore
 //! architecture.check_access_rules(&module_tree).err().unwrap().print(module_tree.tree());
+//!
+//! // or fail a test with the rendered violation, `Display` gives a one line summary
+//! if let Err(violation) = architecture.check_access_rules(&module_tree) {
+//!     panic!("{}", violation.render(&module_tree));
+//! }
+//! ```
+//!
+//! ## Custom access rules
+//! Own rules implement the `AccessRule` trait and inspect the parsed code through `ModuleTree::graph_view`.
+//! ```ignore
+//! #[derive(Debug)]
+//! struct NoUseOf(String);
+//!
+//! impl AccessRule for NoUseOf {
+//!     fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
+//!         match module_tree
+//!             .graph_view()
+//!             .dependencies()
+//!             .find(|use_relation| use_relation.used_object().full_module_path() == &self.0)
+//!         {
+//!             Some(use_relation) => Err(RuleViolation::new(
+//!                 RuleViolationType::SingleLocation,
+//!                 self.violated_rule(),
+//!                 vec![use_relation],
+//!             )),
+//!             None => Ok(()),
+//!         }
+//!     }
+//!
+//!     fn validate(&self, _layer_names: &HashSet<String>) -> bool {
+//!         true
+//!     }
+//! }
+//! ```
+//!
+//! ## Suppressions
+//! Accepted violations are marked in the code with `// archtest:allow(MayNotAccess, reason = "...")` at the end of the line
+//! or in front of an item or module, or with the attribute `#[cfg_attr(archtest, allow_arch(MayNotAccess))]`.
+//! `Architecture::check_access_rules` ignores suppressed violations, `Architecture::report` lists them with the used and unused suppressions.
+//! ```ignore
+//! let report = architecture.report(&module_tree);
+//! report.print(module_tree.tree());
+//! assert!(report.unused_suppressions().is_empty());
 //! ```
 
 extern crate itertools;
@@ -43,8 +89,20 @@ extern crate velcro;
 pub use velcro::hash_set;
 
 pub use crate::analyzer::domain_values::access_rules;
+pub use crate::analyzer::domain_values::{
+    CustomViolation, DiagnosticStyle, LayerCoverage, LayerShare, RuleExplanation,
+    RuleViolationType, Severity, ViolatedRule, ViolationFingerprint,
+};
+pub use crate::analyzer::entities::{Report, RuleViolation, REPORT_SCHEMA_VERSION};
 pub use crate::analyzer::materials::Architecture;
-pub use crate::parser::materials::ModuleTree;
+pub use crate::analyzer::services::rule_builder;
+pub use crate::analyzer::services::{AccessRule, CustomRule};
+pub use crate::parser::domain_values::{
+    GraphEdge, GraphNode, GraphOptions, ItemDependency, LineIndex, ObjectType, ObjectUse,
+    Suppression, SuppressionTarget, UsableObject, UseRelation,
+};
+pub use crate::parser::entities::{DependencyGraph, GraphView, ItemGraph, ModuleGraph, ModuleNode};
+pub use crate::parser::materials::{ModuleTree, MODULE_TREE_SCHEMA_VERSION};
 
 mod analyzer;
 mod parser;
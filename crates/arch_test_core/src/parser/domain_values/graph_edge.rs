@@ -0,0 +1,63 @@
+use crate::parser::domain_values::ObjectType;
+
+/// Dependency between two nodes of a `ModuleGraph`, weighted by the number of uses
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GraphEdge {
+    from: usize,
+    to: usize,
+    weight: usize,
+    highlighted: bool,
+    kinds: Vec<ObjectType>,
+}
+
+impl GraphEdge {
+    pub fn new(from: usize, to: usize, weight: usize, highlighted: bool) -> Self {
+        GraphEdge {
+            from,
+            to,
+            weight,
+            highlighted,
+            kinds: Vec::new(),
+        }
+    }
+
+    pub fn with_endpoints(mut self, from: usize, to: usize) -> Self {
+        self.from = from;
+        self.to = to;
+        self
+    }
+
+    /// Types of the used items, every type is listed once in the order of `ObjectType`
+    pub fn with_kinds(mut self, kinds: impl IntoIterator<Item = ObjectType>) -> Self {
+        let mut kinds: Vec<ObjectType> = kinds.into_iter().collect();
+        kinds.sort_by_key(|kind| *kind as u8);
+        kinds.dedup();
+        self.kinds = kinds;
+        self
+    }
+
+    /// Position of the accessor in the nodes of the graph
+    pub fn from(&self) -> usize {
+        self.from
+    }
+
+    /// Position of the accessed node in the nodes of the graph
+    pub fn to(&self) -> usize {
+        self.to
+    }
+
+    /// Number of uses the edge stands for
+    pub fn weight(&self) -> usize {
+        self.weight
+    }
+
+    /// Whether one of the uses is highlighted by the `GraphOptions`, e.g. because it breaks a rule
+    pub fn highlighted(&self) -> bool {
+        self.highlighted
+    }
+
+    /// Types of the used items, e.g. `Struct` and `Trait`
+    pub fn kinds(&self) -> &Vec<ObjectType> {
+        &self.kinds
+    }
+}
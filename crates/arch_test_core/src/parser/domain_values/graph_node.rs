@@ -0,0 +1,106 @@
+use crate::parser::domain_values::ObjectType;
+
+/// A module, an item or, if collapsed, a layer of a `ModuleGraph`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GraphNode {
+    id: String,
+    name: String,
+    path: String,
+    parent: Option<usize>,
+    layer: Option<String>,
+    file: Option<String>,
+    level: usize,
+    item_count: usize,
+    kind: Option<ObjectType>,
+}
+
+impl GraphNode {
+    pub fn new(id: String, name: String, path: String, parent: Option<usize>) -> Self {
+        GraphNode {
+            id,
+            name,
+            path,
+            parent,
+            layer: None,
+            file: None,
+            level: 0,
+            item_count: 0,
+            kind: None,
+        }
+    }
+
+    pub fn with_parent(mut self, parent: Option<usize>) -> Self {
+        self.parent = parent;
+        self
+    }
+
+    pub fn with_layer(mut self, layer: Option<String>) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    pub fn with_file(mut self, file: Option<String>) -> Self {
+        self.file = file;
+        self
+    }
+
+    pub fn with_level(mut self, level: usize) -> Self {
+        self.level = level;
+        self
+    }
+
+    pub fn with_item_count(mut self, item_count: usize) -> Self {
+        self.item_count = item_count;
+        self
+    }
+
+    pub fn with_kind(mut self, kind: Option<ObjectType>) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Identifier usable in every exported format, e.g. `m3` for the module with index 3
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+
+    /// Module, item or layer name
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    /// Fully qualified module or item path or the layer name
+    pub fn path(&self) -> &String {
+        &self.path
+    }
+
+    /// Position of the enclosing module in the nodes of the graph
+    pub fn parent(&self) -> Option<usize> {
+        self.parent
+    }
+
+    /// Innermost layer of the module if the layers are known, the layer itself if collapsed
+    pub fn layer(&self) -> Option<&String> {
+        self.layer.as_ref()
+    }
+
+    /// File the module or item is defined in, `None` for layers
+    pub fn file(&self) -> Option<&String> {
+        self.file.as_ref()
+    }
+
+    /// Nesting level of the module the node stands for, the crate root has level 0
+    pub fn level(&self) -> usize {
+        self.level
+    }
+
+    /// Number of items defined in the modules the node stands for
+    pub fn item_count(&self) -> usize {
+        self.item_count
+    }
+
+    /// Type of the item, `None` for modules and layers
+    pub fn kind(&self) -> Option<ObjectType> {
+        self.kind
+    }
+}
@@ -0,0 +1,97 @@
+use std::collections::{BTreeSet, HashSet};
+
+/// Selects what `ModuleTree::module_graph` includes, e.g. for `ModuleTree::to_dot_with`
+///
+/// Example:
+/// ```ignore
+/// let options = GraphOptions::default()
+///     .with_subtree("crate::analyzer".to_owned())
+///     .with_max_depth(2);
+/// println!("{}", module_tree.to_dot_with(&options));
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct GraphOptions {
+    layers: Option<BTreeSet<String>>,
+    collapse_layers: bool,
+    neighbourhood: Option<String>,
+    max_depth: Option<usize>,
+    subtree: Option<String>,
+    items: bool,
+    highlighted_uses: HashSet<(usize, usize)>,
+}
+
+impl GraphOptions {
+    /// Assigns every module to the innermost layer it belongs to, e.g. to group the modules by layer
+    pub fn with_layers(mut self, layer_names: impl IntoIterator<Item = String>) -> Self {
+        self.layers = Some(layer_names.into_iter().collect());
+        self
+    }
+
+    /// Collapses every module into its layer, modules without layer are left out
+    pub fn with_collapsed_layers(mut self) -> Self {
+        self.collapse_layers = true;
+        self
+    }
+
+    /// Restricts the graph to the modules of the layer and the modules they depend on or are used by
+    pub fn with_neighbourhood_of(mut self, layer: String) -> Self {
+        self.neighbourhood = Some(layer);
+        self
+    }
+
+    /// Collapses modules deeper than the depth into their ancestor, the root has depth 0
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Restricts the graph to the module with the path, e.g. `crate::analyzer`, and its children
+    pub fn with_subtree(mut self, module_path: String) -> Self {
+        self.subtree = Some(module_path);
+        self
+    }
+
+    /// Shows the items and their dependencies instead of the modules, see `ItemGraph`
+    ///
+    /// Items are not nested, so the maximum depth does not apply.
+    pub fn with_items(mut self) -> Self {
+        self.items = true;
+        self
+    }
+
+    /// Highlights the dependency of the accessor module on the accessed module, e.g. because it breaks a rule
+    pub fn with_highlighted_use(mut self, accessor_index: usize, accessed_index: usize) -> Self {
+        self.highlighted_uses
+            .insert((accessor_index, accessed_index));
+        self
+    }
+
+    pub fn layers(&self) -> Option<&BTreeSet<String>> {
+        self.layers.as_ref()
+    }
+
+    pub fn collapse_layers(&self) -> bool {
+        self.collapse_layers
+    }
+
+    pub fn neighbourhood(&self) -> Option<&String> {
+        self.neighbourhood.as_ref()
+    }
+
+    pub fn max_depth(&self) -> Option<usize> {
+        self.max_depth
+    }
+
+    pub fn subtree(&self) -> Option<&String> {
+        self.subtree.as_ref()
+    }
+
+    pub fn items(&self) -> bool {
+        self.items
+    }
+
+    pub fn is_highlighted(&self, accessor_index: usize, accessed_index: usize) -> bool {
+        self.highlighted_uses
+            .contains(&(accessor_index, accessed_index))
+    }
+}
@@ -0,0 +1,34 @@
+use crate::parser::domain_values::UseRelation;
+
+/// A dependency between two items (struct, enum, trait, function or type alias) of the `ItemGraph`
+///
+/// `using_item` and `used_item` are indices into `ItemGraph::items`.
+/// The `use_relation` points to the location in the source code where the dependency was found.
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct ItemDependency {
+    using_item: usize,
+    used_item: usize,
+    use_relation: UseRelation,
+}
+
+impl ItemDependency {
+    pub fn new(using_item: usize, used_item: usize, use_relation: UseRelation) -> Self {
+        ItemDependency {
+            using_item,
+            used_item,
+            use_relation,
+        }
+    }
+
+    pub fn using_item(&self) -> usize {
+        self.using_item
+    }
+
+    pub fn used_item(&self) -> usize {
+        self.used_item
+    }
+
+    pub fn use_relation(&self) -> &UseRelation {
+        &self.use_relation
+    }
+}
@@ -0,0 +1,48 @@
+use ra_ap_syntax::TextSize;
+
+/// Start offsets and texts of the lines of a file, built once while parsing it
+///
+/// Lines end with `\n` or `\r\n`, the line ending is not part of the line text.
+#[derive(Debug, Clone, Default)]
+pub struct LineIndex {
+    line_starts: Vec<usize>,
+    lines: Vec<String>,
+}
+
+impl LineIndex {
+    pub fn new(content: &str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(content.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        let lines = content
+            .split('\n')
+            .map(|line| line.trim_end_matches('\r').to_owned())
+            .collect();
+        LineIndex { line_starts, lines }
+    }
+
+    /// Line and column of the offset, both starting with 1, the column counts characters
+    pub fn position(&self, offset: TextSize) -> (usize, usize) {
+        let offset = usize::from(offset);
+        let line = self
+            .line_starts
+            .partition_point(|line_start| *line_start <= offset)
+            .max(1);
+        let line_offset = offset - self.line_starts[line - 1];
+        let column = self.lines[line - 1]
+            .char_indices()
+            .take_while(|(index, _)| *index < line_offset)
+            .count()
+            + 1;
+        (line, column)
+    }
+
+    /// Text of the line without its line ending, lines start with 1
+    pub fn line(&self, line: usize) -> Option<&String> {
+        line.checked_sub(1).and_then(|index| self.lines.get(index))
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+}
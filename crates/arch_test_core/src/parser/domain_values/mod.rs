@@ -1,9 +1,21 @@
+pub use self::graph_edge::GraphEdge;
+pub use self::graph_node::GraphNode;
+pub use self::graph_options::GraphOptions;
+pub use self::item_dependency::ItemDependency;
+pub use self::line_index::LineIndex;
 pub use self::object_type::ObjectType;
 pub use self::object_use::ObjectUse;
+pub use self::suppression::{Suppression, SuppressionTarget};
 pub use self::usable_object::UsableObject;
 pub use self::use_relation::UseRelation;
 
+mod graph_edge;
+mod graph_node;
+mod graph_options;
+mod item_dependency;
+mod line_index;
 mod object_type;
 mod object_use;
+mod suppression;
 mod usable_object;
 mod use_relation;
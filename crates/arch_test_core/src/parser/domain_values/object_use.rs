@@ -1,6 +1,11 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use crate::parser::domain_values::usable_object::UsableObject;
+use crate::parser::domain_values::ObjectType;
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ObjectUse {
     node_index: usize,
     full_module_path: String,
@@ -27,4 +32,19 @@ impl ObjectUse {
     pub fn usable_object(&self) -> &UsableObject {
         &self.usable_object
     }
+
+    /// Path of the item this object is or is used in
+    ///
+    /// Uses are attributed to the item they occur in, or to the module if they occur outside of any item.
+    pub fn item_path(&self) -> String {
+        match self.usable_object.object_type() {
+            ObjectType::Use | ObjectType::ImplicitUse | ObjectType::RePublish => {
+                match self.usable_object.owner() {
+                    Some(owner) => format!("{}::{}", self.full_module_path, owner),
+                    None => self.full_module_path.clone(),
+                }
+            }
+            _ => self.full_module_path.clone(),
+        }
+    }
 }
@@ -0,0 +1,104 @@
+use std::fmt::{Display, Formatter};
+
+use ra_ap_syntax::TextRange;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Part of the code a `Suppression` applies to
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SuppressionTarget {
+    /// Objects within the range of the file the suppression was found in, e.g. a line or an item
+    Range(
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::parser::utils::serde_text_range")
+        )]
+        TextRange,
+    ),
+    /// The whole module including its children
+    Module,
+    /// A child module declared with `mod name;`, resolved to `Module` of the child once the tree is parsed
+    ChildModule(String),
+}
+
+/// An accepted violation marked in the code
+///
+/// Either as comment `// archtest:allow(MayNotAccess, reason = "...")` or as attribute `#[allow_arch(MayNotAccess)]`.
+/// A comment at the end of a line applies to the line, a comment or attribute in front of an item or module applies to the whole item or module.
+/// Inner comments `//! archtest:allow(...)` and inner attributes `#![allow_arch(...)]` apply to the enclosing module.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Suppression {
+    rules: Vec<String>,
+    reason: Option<String>,
+    file_path: String,
+    line: usize,
+    target: SuppressionTarget,
+}
+
+impl Suppression {
+    pub fn new(
+        rules: Vec<String>,
+        reason: Option<String>,
+        file_path: String,
+        line: usize,
+        target: SuppressionTarget,
+    ) -> Self {
+        Suppression {
+            rules,
+            reason,
+            file_path,
+            line,
+            target,
+        }
+    }
+
+    /// Names of the suppressed rules, `*` suppresses every rule
+    pub fn rules(&self) -> &Vec<String> {
+        &self.rules
+    }
+
+    pub fn reason(&self) -> Option<&String> {
+        self.reason.as_ref()
+    }
+
+    pub fn file_path(&self) -> &String {
+        &self.file_path
+    }
+
+    /// Line of the comment or attribute, starting with 1
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn target(&self) -> &SuppressionTarget {
+        &self.target
+    }
+
+    pub fn set_target(&mut self, target: SuppressionTarget) {
+        self.target = target;
+    }
+
+    pub fn suppresses(&self, rule_name: &str) -> bool {
+        self.rules
+            .iter()
+            .any(|rule| rule == rule_name || rule == "*")
+    }
+}
+
+impl Display for Suppression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}: allow({})",
+            self.file_path,
+            self.line,
+            self.rules.join(", ")
+        )?;
+        if let Some(reason) = self.reason.as_ref() {
+            write!(f, " because \"{}\"", reason)?;
+        }
+        Ok(())
+    }
+}
@@ -1,15 +1,25 @@
 use std::hash::{Hash, Hasher};
 
 use ra_ap_syntax::TextRange;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
-use crate::parser::domain_values::ObjectType;
+use crate::parser::domain_values::{LineIndex, ObjectType};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UsableObject {
     is_public: bool,
     object_type: ObjectType,
     pub object_name: String,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::parser::utils::serde_text_range")
+    )]
     text_range: TextRange,
+    start_position: (usize, usize),
+    end_position: (usize, usize),
+    owner: Option<String>,
 }
 
 impl UsableObject {
@@ -24,6 +34,9 @@ impl UsableObject {
             object_type,
             object_name,
             text_range,
+            start_position: (0, 0),
+            end_position: (0, 0),
+            owner: None,
         }
     }
 
@@ -42,6 +55,38 @@ impl UsableObject {
     pub fn text_range(&self) -> &TextRange {
         &self.text_range
     }
+
+    /// Line and column the object starts at, both starting with 1, the column counts characters
+    pub fn start_position(&self) -> (usize, usize) {
+        self.start_position
+    }
+
+    /// Line and column after the last character of the object
+    pub fn end_position(&self) -> (usize, usize) {
+        self.end_position
+    }
+
+    /// Converts the text range into line and column positions, done by the parser for every object
+    pub fn resolve_positions(&mut self, line_index: &LineIndex) {
+        self.start_position = line_index.position(self.text_range.start());
+        self.end_position = line_index.position(self.text_range.end());
+    }
+
+    /// Takes over the positions of another object of the same text range, e.g. the use it is expanded from
+    pub fn with_positions_of(mut self, other: &UsableObject) -> Self {
+        self.start_position = other.start_position;
+        self.end_position = other.end_position;
+        self
+    }
+
+    /// Name of the item (struct, enum, trait, function, type alias or implemented type) this object is used in
+    pub fn owner(&self) -> Option<&String> {
+        self.owner.as_ref()
+    }
+
+    pub fn set_owner(&mut self, owner: String) {
+        self.owner = Some(owner);
+    }
 }
 
 impl Hash for UsableObject {
@@ -0,0 +1,127 @@
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+use crate::parser::domain_values::ObjectUse;
+use crate::parser::entities::ModuleNode;
+
+/// Dependencies between the modules of a `ModuleTree`, computed once when the tree is parsed
+///
+/// A module depends on another module if it uses one of its items. Queries include the child modules of the module asked for,
+/// uses between the module and its children are ignored. Modules are referred to by their index in the tree.
+///
+/// Example:
+/// ```ignore
+/// let module_tree = ModuleTree::new("src/lib.rs");
+/// let graph = module_tree.dependency_graph();
+/// let materials = graph.find_module("crate::parser::materials").unwrap();
+/// for dependent in graph.dependents_of(materials, true) {
+///     println!("{}", module_tree.tree()[dependent].get_fully_qualified_path(module_tree.tree()));
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct DependencyGraph {
+    module_indices: HashMap<String, usize>,
+    subtrees: Vec<Vec<usize>>,
+    outgoing: Vec<BTreeSet<usize>>,
+    incoming: Vec<BTreeSet<usize>>,
+}
+
+impl DependencyGraph {
+    pub fn new(tree: &[ModuleNode], possible_uses: &HashMap<String, ObjectUse>) -> Self {
+        let mut dependency_graph = DependencyGraph {
+            module_indices: tree
+                .iter()
+                .map(|node| (node.get_fully_qualified_path(tree), node.index()))
+                .collect(),
+            subtrees: tree
+                .iter()
+                .map(|node| {
+                    let mut subtree = node.included_nodes(tree);
+                    subtree.push(node.index());
+                    subtree.sort_unstable();
+                    subtree
+                })
+                .collect(),
+            outgoing: vec![BTreeSet::new(); tree.len()],
+            incoming: vec![BTreeSet::new(); tree.len()],
+        };
+        for node in tree.iter() {
+            for use_relation in node.use_relations(tree, possible_uses, false) {
+                let used_module = use_relation.used_object().node_index();
+                if used_module != node.index() {
+                    dependency_graph.outgoing[node.index()].insert(used_module);
+                    dependency_graph.incoming[used_module].insert(node.index());
+                }
+            }
+        }
+        dependency_graph
+    }
+
+    /// Index of the module with the fully qualified path, e.g. `crate::parser::materials`
+    pub fn find_module(&self, module_path: &str) -> Option<usize> {
+        self.module_indices.get(module_path).cloned()
+    }
+
+    /// Modules the module or its children use, with `transitive` also the modules those use and so on
+    ///
+    /// The modules are sorted by index, the module and its children are never included.
+    pub fn dependencies_of(&self, node_index: usize, transitive: bool) -> Vec<usize> {
+        self.reachable(&self.outgoing, node_index, transitive)
+    }
+
+    /// Modules using the module or its children, with `transitive` also the modules using those and so on
+    ///
+    /// The modules are sorted by index, the module and its children are never included.
+    pub fn dependents_of(&self, node_index: usize, transitive: bool) -> Vec<usize> {
+        self.reachable(&self.incoming, node_index, transitive)
+    }
+
+    /// Shortest chain of dependencies leading from one module to the other
+    ///
+    /// The chain starts in `from` or one of its children and ends in `to` or one of its children, both ends included.
+    pub fn path_between(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        let mut predecessors: Vec<Option<usize>> = vec![None; self.outgoing.len()];
+        let mut visited = vec![false; self.outgoing.len()];
+        let mut queue = VecDeque::new();
+        for start in self.subtrees[from].iter() {
+            visited[*start] = true;
+            queue.push_back(*start);
+        }
+        while let Some(current) = queue.pop_front() {
+            if self.subtrees[to].binary_search(&current).is_ok() {
+                let mut path = vec![current];
+                while let Some(predecessor) = predecessors[*path.last().unwrap()] {
+                    path.push(predecessor);
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for next in self.outgoing[current].iter() {
+                if !visited[*next] {
+                    visited[*next] = true;
+                    predecessors[*next] = Some(current);
+                    queue.push_back(*next);
+                }
+            }
+        }
+        None
+    }
+
+    fn reachable(
+        &self,
+        adjacency: &[BTreeSet<usize>],
+        start: usize,
+        transitive: bool,
+    ) -> Vec<usize> {
+        let subtree = &self.subtrees[start];
+        let mut visited = BTreeSet::new();
+        let mut stack: Vec<usize> = subtree.clone();
+        while let Some(current) = stack.pop() {
+            for next in adjacency[current].iter() {
+                if subtree.binary_search(next).is_err() && visited.insert(*next) && transitive {
+                    stack.push(*next);
+                }
+            }
+        }
+        visited.into_iter().collect()
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use crate::parser::domain_values::{ItemDependency, ObjectUse, UseRelation};
+use crate::parser::entities::{DependencyGraph, ItemGraph, ModuleNode};
+
+/// Read-only view on the modules, items and dependencies extracted into a `ModuleTree`
+///
+/// This is the entry point for writing custom access rules.
+/// Layer membership follows the same convention as the built-in rules:
+/// a module belongs to a layer if its own name or the name of one of its parents equals the layer name.
+///
+/// Example:
+/// ```ignore
+/// let graph = module_tree.graph_view();
+/// for use_relation in graph.dependencies() {
+///     if graph.module_in_layer(use_relation.using_object().node_index(), "parser")
+///         && graph.module_in_layer(use_relation.used_object().node_index(), "analyzer")
+///     {
+///         println!("{}", use_relation.used_object().full_module_path());
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct GraphView<'t> {
+    tree: &'t [ModuleNode],
+    possible_uses: &'t HashMap<String, ObjectUse>,
+    item_graph: &'t ItemGraph,
+    dependency_graph: &'t DependencyGraph,
+}
+
+impl<'t> GraphView<'t> {
+    pub fn new(
+        tree: &'t [ModuleNode],
+        possible_uses: &'t HashMap<String, ObjectUse>,
+        item_graph: &'t ItemGraph,
+        dependency_graph: &'t DependencyGraph,
+    ) -> Self {
+        GraphView {
+            tree,
+            possible_uses,
+            item_graph,
+            dependency_graph,
+        }
+    }
+
+    pub fn modules(&self) -> impl Iterator<Item = &'t ModuleNode> {
+        self.tree.iter()
+    }
+
+    pub fn module(&self, node_index: usize) -> &'t ModuleNode {
+        &self.tree[node_index]
+    }
+
+    pub fn module_path(&self, node_index: usize) -> String {
+        self.tree[node_index].get_fully_qualified_path(self.tree)
+    }
+
+    /// Items (structs, enums, traits, functions and type aliases) of all modules
+    pub fn items(&self) -> impl Iterator<Item = &'t ObjectUse> {
+        self.item_graph.items().iter()
+    }
+
+    pub fn item_graph(&self) -> &'t ItemGraph {
+        self.item_graph
+    }
+
+    /// Precomputed dependencies between the modules, e.g. for transitive dependencies
+    pub fn dependency_graph(&self) -> &'t DependencyGraph {
+        self.dependency_graph
+    }
+
+    /// Use relations of every module, i.e. the dependencies on module level
+    pub fn dependencies(&self) -> impl Iterator<Item = UseRelation> + 't {
+        let tree = self.tree;
+        let possible_uses = self.possible_uses;
+        tree.iter()
+            .flat_map(move |node| node.use_relations(tree, possible_uses, false))
+    }
+
+    /// Use relations of a single module without the ones of its children
+    pub fn dependencies_of_module(&self, node_index: usize) -> impl Iterator<Item = UseRelation> {
+        self.tree[node_index]
+            .use_relations(self.tree, self.possible_uses, false)
+            .into_iter()
+    }
+
+    /// Dependencies between items
+    pub fn item_dependencies(&self) -> impl Iterator<Item = &'t ItemDependency> {
+        self.item_graph.dependencies().iter()
+    }
+
+    pub fn module_in_layer(&self, node_index: usize, layer: &str) -> bool {
+        let mut current = Some(node_index);
+        while let Some(index) = current {
+            if self.tree[index].module_name() == layer {
+                return true;
+            }
+            current = self.tree[index].parent_index();
+        }
+        false
+    }
+
+    pub fn module_in_any_layer<'l>(
+        &self,
+        node_index: usize,
+        mut layers: impl Iterator<Item = &'l String>,
+    ) -> bool {
+        layers.any(|layer| self.module_in_layer(node_index, layer))
+    }
+
+    pub fn modules_in_layer<'l>(
+        &'l self,
+        layer: &'l str,
+    ) -> impl Iterator<Item = &'t ModuleNode> + 'l {
+        self.tree
+            .iter()
+            .filter(move |node| self.module_in_layer(node.index(), layer))
+    }
+
+    pub fn item_in_layer(&self, item_index: usize, layer: &str) -> bool {
+        self.module_in_layer(self.item_graph.items()[item_index].node_index(), layer)
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::parser::domain_values::{ItemDependency, ObjectType, ObjectUse, UseRelation};
+use crate::parser::entities::ModuleNode;
+
+/// Dependency graph between the items (structs, enums, traits, functions and type aliases) of a `ModuleTree`
+///
+/// Every use of an item inside the definition of another item is a dependency.
+/// Items are sorted by their fully qualified path and referred to by their index.
+///
+/// Example:
+/// ```ignore
+/// let module_tree = ModuleTree::new("src/lib.rs");
+/// let item_graph = module_tree.item_graph();
+/// let item = item_graph.find_item("crate::parser::materials::ModuleTree").unwrap();
+/// for dependency in item_graph.dependents_of(item) {
+///     println!("{}", item_graph.items()[dependency.using_item()].full_module_path());
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct ItemGraph {
+    items: Vec<ObjectUse>,
+    item_indices: HashMap<String, usize>,
+    dependencies: Vec<ItemDependency>,
+    outgoing: Vec<Vec<usize>>,
+    incoming: Vec<Vec<usize>>,
+}
+
+impl ItemGraph {
+    pub fn new(tree: &[ModuleNode], possible_uses: &HashMap<String, ObjectUse>) -> Self {
+        let mut items: Vec<ObjectUse> = possible_uses.values().cloned().collect();
+        items.sort_by(|left, right| left.full_module_path().cmp(right.full_module_path()));
+        let item_indices: HashMap<String, usize> = items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| (item.full_module_path().clone(), index))
+            .collect();
+
+        let mut item_graph = ItemGraph {
+            outgoing: vec![Vec::new(); items.len()],
+            incoming: vec![Vec::new(); items.len()],
+            items,
+            item_indices,
+            dependencies: Vec::new(),
+        };
+
+        let mut known_dependencies = HashSet::new();
+        for node in tree.iter() {
+            let module_path = node.get_fully_qualified_path(tree);
+            for obj in node
+                .usable_objects()
+                .iter()
+                .filter(|obj| obj.object_type() == ObjectType::ImplicitUse)
+            {
+                let using_item = obj
+                    .owner()
+                    .and_then(|owner| item_graph.resolve_item(owner, node, &module_path));
+                let used_item = item_graph.resolve_item(&obj.object_name, node, &module_path);
+                if let (Some(using_item), Some(used_item)) = (using_item, used_item) {
+                    if using_item == used_item
+                        || !known_dependencies.insert((using_item, used_item))
+                    {
+                        continue;
+                    }
+                    let dependency_index = item_graph.dependencies.len();
+                    item_graph.dependencies.push(ItemDependency::new(
+                        using_item,
+                        used_item,
+                        UseRelation::new(
+                            ObjectUse::new(node.index(), module_path.clone(), obj.clone()),
+                            item_graph.items[used_item].clone(),
+                        ),
+                    ));
+                    item_graph.outgoing[using_item].push(dependency_index);
+                    item_graph.incoming[used_item].push(dependency_index);
+                }
+            }
+        }
+        item_graph
+    }
+
+    fn resolve_item(&self, name: &str, node: &ModuleNode, module_path: &str) -> Option<usize> {
+        if let Some(index) = self
+            .item_indices
+            .get(&format!("{}::{}", module_path, name))
+            .or_else(|| self.item_indices.get(name))
+        {
+            return Some(*index);
+        }
+        // The name may have been imported, e.g. the implemented type of an impl block
+        let suffix = format!("::{}", name);
+        if let Some(index) = node
+            .usable_objects()
+            .iter()
+            .filter(|obj| {
+                obj.object_type() == ObjectType::Use
+                    || obj.object_type() == ObjectType::RePublish
+                    || obj.object_type() == ObjectType::ImplicitUse
+            })
+            .filter(|obj| obj.object_name.ends_with(&suffix))
+            .find_map(|obj| self.item_indices.get(&obj.object_name).cloned())
+        {
+            return Some(index);
+        }
+        // Associated items like `Test1::new` are attributed to `Test1`
+        name.rsplit_once("::")
+            .and_then(|(prefix, _)| self.resolve_item(prefix, node, module_path))
+    }
+
+    pub fn items(&self) -> &Vec<ObjectUse> {
+        &self.items
+    }
+
+    pub fn dependencies(&self) -> &Vec<ItemDependency> {
+        &self.dependencies
+    }
+
+    pub fn find_item(&self, full_path: &str) -> Option<usize> {
+        self.item_indices.get(full_path).cloned()
+    }
+
+    pub fn items_in_module(&self, node_index: usize) -> impl Iterator<Item = usize> + '_ {
+        self.items
+            .iter()
+            .enumerate()
+            .filter(move |(_, item)| item.node_index() == node_index)
+            .map(|(index, _)| index)
+    }
+
+    /// Dependencies where the item is the user
+    pub fn dependencies_of(&self, item_index: usize) -> impl Iterator<Item = &ItemDependency> {
+        self.outgoing[item_index]
+            .iter()
+            .map(move |dependency_index| &self.dependencies[*dependency_index])
+    }
+
+    /// Dependencies where the item is the one being used
+    pub fn dependents_of(&self, item_index: usize) -> impl Iterator<Item = &ItemDependency> {
+        self.incoming[item_index]
+            .iter()
+            .map(move |dependency_index| &self.dependencies[*dependency_index])
+    }
+}
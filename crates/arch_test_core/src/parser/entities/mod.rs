@@ -1,3 +1,11 @@
+pub use self::dependency_graph::DependencyGraph;
+pub use self::graph_view::GraphView;
+pub use self::item_graph::ItemGraph;
+pub use self::module_graph::ModuleGraph;
 pub use self::module_node::ModuleNode;
 
+mod dependency_graph;
+mod graph_view;
+mod item_graph;
+mod module_graph;
 pub mod module_node;
@@ -0,0 +1,817 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::parser::domain_values::{GraphEdge, GraphNode, GraphOptions, ObjectType};
+use crate::parser::entities::GraphView;
+
+/// Modules and their dependencies as selected by `GraphOptions`, the base of the exported graph formats
+///
+/// Nodes are modules, layers if collapsed or items if selected, and know their enclosing module.
+/// Edges stand for all uses between two nodes and are weighted by their number.
+#[derive(Debug, Clone, Default)]
+pub struct ModuleGraph {
+    nodes: Vec<GraphNode>,
+    edges: Vec<GraphEdge>,
+    grouped_by_layer: bool,
+}
+
+/// Attributes of the nodes and edges in GraphML and GEXF: id, domain and GraphML type, see `gexf_type`
+const GRAPHML_KEYS: [(&str, &str, &str); 11] = [
+    ("name", "node", "string"),
+    ("path", "node", "string"),
+    ("parent", "node", "string"),
+    ("file", "node", "string"),
+    ("level", "node", "int"),
+    ("layer", "node", "string"),
+    ("item_count", "node", "int"),
+    ("kind", "node", "string"),
+    ("weight", "edge", "int"),
+    ("kinds", "edge", "string"),
+    ("highlighted", "edge", "boolean"),
+];
+
+impl ModuleGraph {
+    pub fn new(graph_view: GraphView<'_>, options: &GraphOptions) -> Self {
+        let root = match options.subtree() {
+            Some(subtree) => match graph_view
+                .modules()
+                .find(|node| graph_view.module_path(node.index()) == *subtree)
+            {
+                Some(node) => Some(node.index()),
+                None => return ModuleGraph::default(),
+            },
+            None => None,
+        };
+
+        let module_layers: Vec<Option<&String>> = graph_view
+            .modules()
+            .map(|node| {
+                options
+                    .layers()
+                    .and_then(|layers| innermost_layer(graph_view, node.index(), layers))
+            })
+            .collect();
+        let module_graph = if options.items() {
+            ModuleGraph::of_items(graph_view, options, root, &module_layers)
+        } else {
+            ModuleGraph::of_modules(graph_view, options, root, &module_layers)
+        };
+        match options.neighbourhood() {
+            Some(layer) => module_graph.neighbourhood_of(layer),
+            None => module_graph,
+        }
+    }
+
+    fn of_modules(
+        graph_view: GraphView<'_>,
+        options: &GraphOptions,
+        root: Option<usize>,
+        module_layers: &[Option<&String>],
+    ) -> Self {
+        let mut nodes = Vec::new();
+        let mut representatives: Vec<Option<usize>> = vec![None; module_layers.len()];
+        if options.collapse_layers() {
+            let used_layers: BTreeSet<&String> = module_layers
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| depth_within(graph_view, *index, root).is_some())
+                .filter_map(|(_, layer)| *layer)
+                .collect();
+            for (position, layer) in used_layers.iter().enumerate() {
+                nodes.push(
+                    GraphNode::new(
+                        format!("l{}", position),
+                        (*layer).clone(),
+                        (*layer).clone(),
+                        None,
+                    )
+                    .with_layer(Some((*layer).clone())),
+                );
+            }
+            for (index, layer) in module_layers.iter().enumerate() {
+                representatives[index] = layer
+                    .filter(|_| depth_within(graph_view, index, root).is_some())
+                    .and_then(|layer| used_layers.iter().position(|used| *used == layer));
+            }
+        } else {
+            // Parents are parsed before their children, so their representative is known already
+            for node in graph_view.modules() {
+                let depth = match depth_within(graph_view, node.index(), root) {
+                    Some(depth) => depth,
+                    None => continue,
+                };
+                let parent = node
+                    .parent_index()
+                    .filter(|_| depth > 0)
+                    .and_then(|parent_index| representatives[parent_index]);
+                if options
+                    .max_depth()
+                    .is_some_and(|max_depth| depth > max_depth)
+                {
+                    representatives[node.index()] = parent;
+                    continue;
+                }
+                representatives[node.index()] = Some(nodes.len());
+                nodes.push(
+                    GraphNode::new(
+                        format!("m{}", node.index()),
+                        node.module_name().clone(),
+                        graph_view.module_path(node.index()),
+                        parent,
+                    )
+                    .with_layer(module_layers[node.index()].cloned())
+                    .with_file(Some(node.file_path().clone()))
+                    .with_level(node.level()),
+                );
+            }
+        }
+
+        let mut item_counts = vec![0; nodes.len()];
+        for item in graph_view.items() {
+            if let Some(representative) = representatives[item.node_index()] {
+                item_counts[representative] += 1;
+            }
+        }
+        let nodes = nodes
+            .into_iter()
+            .zip(item_counts)
+            .map(|(node, item_count)| node.with_item_count(item_count))
+            .collect();
+
+        let mut weights: BTreeMap<(usize, usize), (usize, bool, Vec<ObjectType>)> = BTreeMap::new();
+        for use_relation in graph_view.dependencies() {
+            let accessor_index = use_relation.using_object().node_index();
+            let accessed_index = use_relation.used_object().node_index();
+            if let (Some(from), Some(to)) = (
+                representatives[accessor_index],
+                representatives[accessed_index],
+            ) {
+                if from == to {
+                    continue;
+                }
+                let (weight, highlighted, kinds) =
+                    weights.entry((from, to)).or_insert((0, false, Vec::new()));
+                *weight += 1;
+                *highlighted |= options.is_highlighted(accessor_index, accessed_index);
+                kinds.push(use_relation.used_object().usable_object().object_type());
+            }
+        }
+        let edges = weights
+            .into_iter()
+            .map(|((from, to), (weight, highlighted, kinds))| {
+                GraphEdge::new(from, to, weight, highlighted).with_kinds(kinds)
+            })
+            .collect();
+        ModuleGraph::grouped(nodes, edges, options)
+    }
+
+    /// Every item of the modules within the subtree is a node, every dependency between two of them an edge
+    fn of_items(
+        graph_view: GraphView<'_>,
+        options: &GraphOptions,
+        root: Option<usize>,
+        module_layers: &[Option<&String>],
+    ) -> Self {
+        let mut positions = Vec::new();
+        let mut nodes = Vec::new();
+        for (index, item) in graph_view.items().enumerate() {
+            if depth_within(graph_view, item.node_index(), root).is_none() {
+                positions.push(None);
+                continue;
+            }
+            let module = graph_view.module(item.node_index());
+            positions.push(Some(nodes.len()));
+            nodes.push(
+                GraphNode::new(
+                    format!("i{}", index),
+                    item.usable_object().object_name().clone(),
+                    item.full_module_path().clone(),
+                    None,
+                )
+                .with_layer(module_layers[item.node_index()].cloned())
+                .with_file(Some(module.file_path().clone()))
+                .with_level(module.level())
+                .with_kind(Some(item.usable_object().object_type())),
+            );
+        }
+        let edges = graph_view
+            .item_dependencies()
+            .filter_map(|dependency| {
+                let use_relation = dependency.use_relation();
+                Some(
+                    GraphEdge::new(
+                        positions[dependency.using_item()]?,
+                        positions[dependency.used_item()]?,
+                        1,
+                        options.is_highlighted(
+                            use_relation.using_object().node_index(),
+                            use_relation.used_object().node_index(),
+                        ),
+                    )
+                    .with_kinds(vec![use_relation
+                        .used_object()
+                        .usable_object()
+                        .object_type()]),
+                )
+            })
+            .collect();
+        ModuleGraph::grouped(nodes, edges, options)
+    }
+
+    /// Groups the nodes by layer when rendered if they are assigned to layers but not collapsed into them
+    fn grouped(nodes: Vec<GraphNode>, edges: Vec<GraphEdge>, options: &GraphOptions) -> Self {
+        let grouped_by_layer =
+            !options.collapse_layers() && nodes.iter().any(|node| node.layer().is_some());
+        ModuleGraph {
+            nodes,
+            edges,
+            grouped_by_layer,
+        }
+    }
+
+    /// Keeps the nodes of the layer, the nodes connected to them and the edges between both
+    ///
+    /// Enclosing modules are kept as well, so the remaining nodes stay in place.
+    fn neighbourhood_of(self, layer: &str) -> Self {
+        let in_layer = |index: usize| self.nodes[index].layer().map(String::as_str) == Some(layer);
+        let edges: Vec<GraphEdge> = self
+            .edges
+            .iter()
+            .filter(|edge| in_layer(edge.from()) || in_layer(edge.to()))
+            .cloned()
+            .collect();
+        let mut keep: Vec<bool> = (0..self.nodes.len()).map(in_layer).collect();
+        for edge in edges.iter() {
+            keep[edge.from()] = true;
+            keep[edge.to()] = true;
+        }
+        for index in 0..self.nodes.len() {
+            if keep[index] {
+                let mut parent = self.nodes[index].parent();
+                while let Some(parent_index) = parent {
+                    keep[parent_index] = true;
+                    parent = self.nodes[parent_index].parent();
+                }
+            }
+        }
+
+        let mut positions = vec![None; self.nodes.len()];
+        let mut nodes = Vec::new();
+        for (index, node) in self.nodes.iter().enumerate() {
+            if keep[index] {
+                positions[index] = Some(nodes.len());
+                nodes.push(
+                    node.clone().with_parent(
+                        node.parent()
+                            .and_then(|parent_index| positions[parent_index]),
+                    ),
+                );
+            }
+        }
+        let edges = edges
+            .into_iter()
+            .filter_map(|edge| {
+                let (from, to) = (positions[edge.from()]?, positions[edge.to()]?);
+                Some(edge.with_endpoints(from, to))
+            })
+            .collect();
+        ModuleGraph {
+            nodes,
+            edges,
+            grouped_by_layer: self.grouped_by_layer,
+        }
+    }
+
+    pub fn nodes(&self) -> &Vec<GraphNode> {
+        &self.nodes
+    }
+
+    pub fn edges(&self) -> &Vec<GraphEdge> {
+        &self.edges
+    }
+
+    /// Positions of the nodes directly enclosed by the node
+    pub fn children(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(move |(_, node)| node.parent() == Some(index))
+            .map(|(child_index, _)| child_index)
+    }
+
+    /// Positions of the nodes without enclosing node
+    pub fn roots(&self) -> impl Iterator<Item = usize> + '_ {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| node.parent().is_none())
+            .map(|(index, _)| index)
+    }
+
+    /// The graph in the DOT language of Graphviz
+    ///
+    /// Modules with children become clusters, edges are labelled with their weight and highlighted edges are red.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph architecture {\n    node [shape=box];\n");
+        for index in self.roots() {
+            self.write_dot_node(&mut dot, index, 1);
+        }
+        for edge in self.edges.iter() {
+            let mut attributes = vec![
+                format!("weight={}", edge.weight()),
+                format!("label=\"{}\"", edge.weight()),
+            ];
+            if edge.highlighted() {
+                attributes.push("color=red".to_owned());
+                attributes.push("fontcolor=red".to_owned());
+                attributes.push("penwidth=2".to_owned());
+            }
+            dot.push_str(&format!(
+                "    {} -> {} [{}];\n",
+                self.nodes[edge.from()].id(),
+                self.nodes[edge.to()].id(),
+                attributes.join(", ")
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// The graph as Mermaid flowchart
+    ///
+    /// If the layers are known the modules are grouped into a subgraph per layer,
+    /// otherwise modules with children become subgraphs like the clusters of `to_dot`.
+    pub fn to_mermaid(&self) -> String {
+        let mut mermaid = String::from("flowchart LR\n");
+        if self.grouped_by_layer {
+            let (groups, ungrouped) = self.layer_groups();
+            for (position, (layer, members)) in groups.iter().enumerate() {
+                mermaid.push_str(&format!(
+                    "    subgraph layer_{}[\"{}\"]\n",
+                    position,
+                    escape_mermaid(layer)
+                ));
+                for member in members {
+                    mermaid.push_str(&self.mermaid_node(*member, true, 2));
+                }
+                mermaid.push_str("    end\n");
+            }
+            for index in ungrouped {
+                mermaid.push_str(&self.mermaid_node(index, true, 1));
+            }
+        } else {
+            for index in self.roots() {
+                self.write_mermaid_node(&mut mermaid, index, 1);
+            }
+        }
+        let mut highlighted = Vec::new();
+        for (position, edge) in self.edges.iter().enumerate() {
+            mermaid.push_str(&format!(
+                "    {} -->|{}| {}\n",
+                self.nodes[edge.from()].id(),
+                edge.weight(),
+                self.nodes[edge.to()].id()
+            ));
+            if edge.highlighted() {
+                highlighted.push(position.to_string());
+            }
+        }
+        if !highlighted.is_empty() {
+            mermaid.push_str(&format!(
+                "    linkStyle {} stroke:red,stroke-width:2px\n",
+                highlighted.join(",")
+            ));
+        }
+        mermaid
+    }
+
+    /// The graph as PlantUML component diagram
+    ///
+    /// Layers or, if the layers are not known, modules with children become packages.
+    pub fn to_plantuml(&self) -> String {
+        let mut plantuml = String::from("@startuml\nskinparam componentStyle rectangle\n");
+        if self.grouped_by_layer {
+            let (groups, ungrouped) = self.layer_groups();
+            for (layer, members) in groups.iter() {
+                plantuml.push_str(&format!("package \"{}\" {{\n", layer));
+                for member in members {
+                    plantuml.push_str(&self.plantuml_node(*member, true, 1));
+                }
+                plantuml.push_str("}\n");
+            }
+            for index in ungrouped {
+                plantuml.push_str(&self.plantuml_node(index, true, 0));
+            }
+        } else {
+            for index in self.roots() {
+                self.write_plantuml_node(&mut plantuml, index, 0);
+            }
+        }
+        for edge in self.edges.iter() {
+            plantuml.push_str(&format!(
+                "{} {} {} : {}\n",
+                self.nodes[edge.from()].id(),
+                if edge.highlighted() {
+                    "-[#red,bold]->"
+                } else {
+                    "-->"
+                },
+                self.nodes[edge.to()].id(),
+                edge.weight()
+            ));
+        }
+        plantuml.push_str("@enduml\n");
+        plantuml
+    }
+
+    /// The graph as Structurizr DSL workspace for the C4 model
+    ///
+    /// Every node becomes a component of one container named like the workspace, grouped by layer if the layers are known.
+    /// Relationships are described by their number of uses, highlighted ones are tagged `Violation` and drawn red.
+    pub fn to_structurizr(&self, name: &str) -> String {
+        let name = escape_structurizr(name);
+        let mut dsl = format!(
+            "workspace \"{}\" {{\n    model {{\n        system = softwareSystem \"{}\" {{\n            application = container \"{}\" \"\" \"Rust\" {{\n",
+            name, name, name
+        );
+        if self.grouped_by_layer {
+            let (groups, ungrouped) = self.layer_groups();
+            for (layer, members) in groups.iter() {
+                dsl.push_str(&format!(
+                    "                group \"{}\" {{\n",
+                    escape_structurizr(layer)
+                ));
+                for member in members {
+                    dsl.push_str(&self.structurizr_component(*member, 5));
+                }
+                dsl.push_str("                }\n");
+            }
+            for index in ungrouped {
+                dsl.push_str(&self.structurizr_component(index, 4));
+            }
+        } else {
+            for index in 0..self.nodes.len() {
+                dsl.push_str(&self.structurizr_component(index, 4));
+            }
+        }
+        dsl.push_str("            }\n        }\n");
+        for edge in self.edges.iter() {
+            dsl.push_str(&format!(
+                "        {} -> {} \"{}\" \"Rust\"{}\n",
+                self.nodes[edge.from()].id(),
+                self.nodes[edge.to()].id(),
+                counted(edge.weight(), "use"),
+                if edge.highlighted() {
+                    " \"Violation\""
+                } else {
+                    ""
+                }
+            ));
+        }
+        dsl.push_str("    }\n    views {\n        component application \"Components\" {\n");
+        dsl.push_str(
+            "            include *\n            autoLayout lr\n        }\n        styles {\n",
+        );
+        dsl.push_str("            relationship \"Violation\" {\n                color #ff0000\n");
+        dsl.push_str("                thickness 4\n            }\n        }\n    }\n}\n");
+        dsl
+    }
+
+    fn structurizr_component(&self, index: usize, depth: usize) -> String {
+        let node = &self.nodes[index];
+        let description = match node.kind() {
+            Some(kind) => format!("{:?}", kind),
+            None => counted(node.item_count(), "item"),
+        };
+        format!(
+            "{}{} = component \"{}\" \"{}\" \"Rust\"\n",
+            "    ".repeat(depth),
+            node.id(),
+            escape_structurizr(node.path()),
+            description
+        )
+    }
+
+    /// The graph as GraphML document, e.g. for yEd
+    ///
+    /// Nodes carry their path, file, level, layer, item count and item kind as data,
+    /// edges their weight, the kinds of the used items and whether they are highlighted.
+    pub fn to_graphml(&self) -> String {
+        let mut graphml = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n",
+        );
+        for (id, domain, attribute_type) in GRAPHML_KEYS.iter() {
+            graphml.push_str(&format!(
+                "  <key id=\"{}\" for=\"{}\" attr.name=\"{}\" attr.type=\"{}\"/>\n",
+                id, domain, id, attribute_type
+            ));
+        }
+        graphml.push_str("  <graph id=\"architecture\" edgedefault=\"directed\">\n");
+        for node in self.nodes.iter() {
+            graphml.push_str(&format!("    <node id=\"{}\">\n", node.id()));
+            for (key, value) in self.node_attributes(node) {
+                graphml.push_str(&format!(
+                    "      <data key=\"{}\">{}</data>\n",
+                    key,
+                    escape_xml(&value)
+                ));
+            }
+            graphml.push_str("    </node>\n");
+        }
+        for (position, edge) in self.edges.iter().enumerate() {
+            graphml.push_str(&format!(
+                "    <edge id=\"e{}\" source=\"{}\" target=\"{}\">\n      <data key=\"weight\">{}</data>\n",
+                position,
+                self.nodes[edge.from()].id(),
+                self.nodes[edge.to()].id(),
+                edge.weight()
+            ));
+            for (key, value) in edge_attributes(edge) {
+                graphml.push_str(&format!(
+                    "      <data key=\"{}\">{}</data>\n",
+                    key,
+                    escape_xml(&value)
+                ));
+            }
+            graphml.push_str("    </edge>\n");
+        }
+        graphml.push_str("  </graph>\n</graphml>\n");
+        graphml
+    }
+
+    /// The graph as GEXF 1.3 document, e.g. for Gephi
+    ///
+    /// Carries the same attributes as `to_graphml`, enclosing modules are given as parent id.
+    pub fn to_gexf(&self) -> String {
+        let mut gexf = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<gexf xmlns=\"http://gexf.net/1.3\" version=\"1.3\">\n",
+        );
+        gexf.push_str("  <graph defaultedgetype=\"directed\" mode=\"static\">\n");
+        for class in ["node", "edge"].iter() {
+            gexf.push_str(&format!("    <attributes class=\"{}\">\n", class));
+            for (id, domain, attribute_type) in GRAPHML_KEYS.iter() {
+                if domain == class && *id != "name" && *id != "weight" {
+                    gexf.push_str(&format!(
+                        "      <attribute id=\"{}\" title=\"{}\" type=\"{}\"/>\n",
+                        id,
+                        id,
+                        gexf_type(attribute_type)
+                    ));
+                }
+            }
+            gexf.push_str("    </attributes>\n");
+        }
+        gexf.push_str("    <nodes>\n");
+        for node in self.nodes.iter() {
+            gexf.push_str(&format!(
+                "      <node id=\"{}\" label=\"{}\"",
+                node.id(),
+                escape_xml(node.name())
+            ));
+            if let Some(parent) = node.parent() {
+                gexf.push_str(&format!(" pid=\"{}\"", self.nodes[parent].id()));
+            }
+            gexf.push_str(">\n        <attvalues>\n");
+            for (key, value) in self.node_attributes(node).into_iter().skip(1) {
+                gexf.push_str(&format!(
+                    "          <attvalue for=\"{}\" value=\"{}\"/>\n",
+                    key,
+                    escape_xml(&value)
+                ));
+            }
+            gexf.push_str("        </attvalues>\n      </node>\n");
+        }
+        gexf.push_str("    </nodes>\n    <edges>\n");
+        for (position, edge) in self.edges.iter().enumerate() {
+            gexf.push_str(&format!(
+                "      <edge id=\"e{}\" source=\"{}\" target=\"{}\" weight=\"{}\">\n        <attvalues>\n",
+                position,
+                self.nodes[edge.from()].id(),
+                self.nodes[edge.to()].id(),
+                edge.weight()
+            ));
+            for (key, value) in edge_attributes(edge) {
+                gexf.push_str(&format!(
+                    "          <attvalue for=\"{}\" value=\"{}\"/>\n",
+                    key,
+                    escape_xml(&value)
+                ));
+            }
+            gexf.push_str("        </attvalues>\n      </edge>\n");
+        }
+        gexf.push_str("    </edges>\n  </graph>\n</gexf>\n");
+        gexf
+    }
+
+    /// Node data of GraphML and GEXF in the order of `GRAPHML_KEYS`, absent values are left out
+    fn node_attributes(&self, node: &GraphNode) -> Vec<(&'static str, String)> {
+        let mut attributes = vec![("name", node.name().clone()), ("path", node.path().clone())];
+        if let Some(parent) = node.parent() {
+            attributes.push(("parent", self.nodes[parent].path().clone()));
+        }
+        if let Some(file) = node.file() {
+            attributes.push(("file", file.clone()));
+        }
+        attributes.push(("level", node.level().to_string()));
+        if let Some(layer) = node.layer() {
+            attributes.push(("layer", layer.clone()));
+        }
+        match node.kind() {
+            Some(kind) => attributes.push(("kind", format!("{:?}", kind))),
+            None => attributes.push(("item_count", node.item_count().to_string())),
+        }
+        attributes
+    }
+
+    /// Nodes by layer in the order of the layer names and the nodes without layer
+    fn layer_groups(&self) -> (BTreeMap<&String, Vec<usize>>, Vec<usize>) {
+        let mut groups: BTreeMap<&String, Vec<usize>> = BTreeMap::new();
+        let mut ungrouped = Vec::new();
+        for (index, node) in self.nodes.iter().enumerate() {
+            match node.layer() {
+                Some(layer) => groups.entry(layer).or_default().push(index),
+                None => ungrouped.push(index),
+            }
+        }
+        (groups, ungrouped)
+    }
+
+    fn mermaid_node(&self, index: usize, with_path: bool, depth: usize) -> String {
+        let node = &self.nodes[index];
+        format!(
+            "{}{}[\"{}\"]\n",
+            "    ".repeat(depth),
+            node.id(),
+            escape_mermaid(if with_path { node.path() } else { node.name() })
+        )
+    }
+
+    fn write_mermaid_node(&self, mermaid: &mut String, index: usize, depth: usize) {
+        let children: Vec<usize> = self.children(index).collect();
+        if children.is_empty() {
+            mermaid.push_str(&self.mermaid_node(index, false, depth));
+            return;
+        }
+        let indentation = "    ".repeat(depth);
+        let node = &self.nodes[index];
+        mermaid.push_str(&format!(
+            "{}subgraph {}_children[\"{}\"]\n",
+            indentation,
+            node.id(),
+            escape_mermaid(node.path())
+        ));
+        mermaid.push_str(&self.mermaid_node(index, false, depth + 1));
+        for child in children {
+            self.write_mermaid_node(mermaid, child, depth + 1);
+        }
+        mermaid.push_str(&format!("{}end\n", indentation));
+    }
+
+    fn plantuml_node(&self, index: usize, with_path: bool, depth: usize) -> String {
+        let node = &self.nodes[index];
+        format!(
+            "{}[{}] as {}\n",
+            "  ".repeat(depth),
+            if with_path { node.path() } else { node.name() },
+            node.id()
+        )
+    }
+
+    fn write_plantuml_node(&self, plantuml: &mut String, index: usize, depth: usize) {
+        let children: Vec<usize> = self.children(index).collect();
+        if children.is_empty() {
+            plantuml.push_str(&self.plantuml_node(index, false, depth));
+            return;
+        }
+        let indentation = "  ".repeat(depth);
+        plantuml.push_str(&format!(
+            "{}package \"{}\" {{\n",
+            indentation,
+            self.nodes[index].path()
+        ));
+        plantuml.push_str(&self.plantuml_node(index, false, depth + 1));
+        for child in children {
+            self.write_plantuml_node(plantuml, child, depth + 1);
+        }
+        plantuml.push_str(&format!("{}}}\n", indentation));
+    }
+
+    fn write_dot_node(&self, dot: &mut String, index: usize, depth: usize) {
+        let indentation = "    ".repeat(depth);
+        let node = &self.nodes[index];
+        let children: Vec<usize> = self.children(index).collect();
+        if children.is_empty() {
+            dot.push_str(&format!(
+                "{}{} [label=\"{}\"];\n",
+                indentation,
+                node.id(),
+                escape_dot(node.name())
+            ));
+            return;
+        }
+        dot.push_str(&format!(
+            "{}subgraph cluster_{} {{\n{}    label=\"{}\";\n{}    {} [label=\"{}\"];\n",
+            indentation,
+            node.id(),
+            indentation,
+            escape_dot(node.path()),
+            indentation,
+            node.id(),
+            escape_dot(node.name())
+        ));
+        for child in children {
+            self.write_dot_node(dot, child, depth + 1);
+        }
+        dot.push_str(&format!("{}}}\n", indentation));
+    }
+}
+
+/// Number of parents up to the root, `None` if the module is not within the subtree of the root
+fn depth_within(
+    graph_view: GraphView<'_>,
+    node_index: usize,
+    root: Option<usize>,
+) -> Option<usize> {
+    let mut depth = 0;
+    let mut current = node_index;
+    loop {
+        if Some(current) == root {
+            return Some(depth);
+        }
+        match graph_view.module(current).parent_index() {
+            Some(parent_index) => {
+                current = parent_index;
+                depth += 1;
+            }
+            None if root.is_none() => return Some(depth),
+            None => return None,
+        }
+    }
+}
+
+/// The layer of the module or of its closest parent being a layer
+fn innermost_layer<'l>(
+    graph_view: GraphView<'_>,
+    node_index: usize,
+    layers: &'l BTreeSet<String>,
+) -> Option<&'l String> {
+    let mut current = Some(node_index);
+    while let Some(index) = current {
+        if let Some(layer) = layers.get(graph_view.module(index).module_name()) {
+            return Some(layer);
+        }
+        current = graph_view.module(index).parent_index();
+    }
+    None
+}
+
+/// Edge data of GraphML and GEXF besides the weight
+fn edge_attributes(edge: &GraphEdge) -> Vec<(&'static str, String)> {
+    vec![
+        (
+            "kinds",
+            edge.kinds()
+                .iter()
+                .map(|kind| format!("{:?}", kind))
+                .collect::<Vec<String>>()
+                .join(","),
+        ),
+        ("highlighted", edge.highlighted().to_string()),
+    ]
+}
+
+/// GEXF name of a GraphML attribute type
+fn gexf_type(graphml_type: &str) -> &str {
+    match graphml_type {
+        "int" => "integer",
+        other => other,
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// The count with the noun in singular or plural, e.g. `1 use` and `2 uses`
+fn counted(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("{} {}", count, noun)
+    } else {
+        format!("{} {}s", count, noun)
+    }
+}
+
+fn escape_structurizr(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_mermaid(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
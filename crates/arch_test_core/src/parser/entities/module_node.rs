@@ -1,8 +1,14 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::parser::domain_values::{ObjectType, ObjectUse, UsableObject, UseRelation};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::parser::domain_values::{
+    LineIndex, ObjectType, ObjectUse, Suppression, UsableObject, UseRelation,
+};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ModuleNode {
     index: usize,
     parent_index: Option<usize>,
@@ -11,6 +17,10 @@ pub struct ModuleNode {
     module_name: String,
     children: Vec<usize>,
     pub usable_objects: Vec<UsableObject>,
+    pub suppressions: Vec<Suppression>,
+    /// Not serialized, `ModuleTree` rebuilds it from the source file when loading
+    #[cfg_attr(feature = "serde", serde(skip))]
+    line_index: LineIndex,
 }
 
 impl ModuleNode {
@@ -29,6 +39,8 @@ impl ModuleNode {
             module_name,
             children: vec![],
             usable_objects: vec![],
+            suppressions: vec![],
+            line_index: LineIndex::default(),
         }
     }
 
@@ -112,4 +124,22 @@ impl ModuleNode {
     pub fn usable_objects(&self) -> &Vec<UsableObject> {
         &self.usable_objects
     }
+
+    /// Lines of the file of the module, empty for directories without `mod.rs`
+    pub fn line_index(&self) -> &LineIndex {
+        &self.line_index
+    }
+
+    /// Stores the lines of the file and resolves the positions of the usable objects with them
+    pub fn set_line_index(&mut self, line_index: LineIndex) {
+        for usable_object in self.usable_objects.iter_mut() {
+            usable_object.resolve_positions(&line_index);
+        }
+        self.line_index = line_index;
+    }
+
+    /// Suppressions found in this module, see `Suppression`
+    pub fn suppressions(&self) -> &Vec<Suppression> {
+        &self.suppressions
+    }
 }
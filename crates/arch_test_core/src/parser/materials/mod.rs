@@ -1,3 +1,3 @@
-pub use self::module_tree::ModuleTree;
+pub use self::module_tree::{ModuleTree, MODULE_TREE_SCHEMA_VERSION};
 
 mod module_tree;
@@ -1,20 +1,108 @@
+#[cfg(feature = "serde")]
+use std::collections::BTreeMap;
 use std::collections::HashMap;
+#[cfg(feature = "serde")]
+use std::convert::TryFrom;
+#[cfg(feature = "serde")]
+use std::fs;
 use std::path::Path;
 
-use crate::parser::domain_values::{ObjectType, ObjectUse, UsableObject};
-use crate::parser::entities::ModuleNode;
+#[cfg(feature = "serde")]
+use serde::ser::SerializeStruct;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize, Serializer};
+
+#[cfg(feature = "serde")]
+use crate::parser::domain_values::LineIndex;
+use crate::parser::domain_values::{
+    GraphOptions, ObjectType, ObjectUse, SuppressionTarget, UsableObject,
+};
+use crate::parser::entities::{DependencyGraph, GraphView, ItemGraph, ModuleGraph, ModuleNode};
 use crate::parser::services::parse_main_or_mod_file_into_tree;
 
+/// Version of the schema `ModuleTree` is serialized with, increased on incompatible changes
+pub const MODULE_TREE_SCHEMA_VERSION: usize = 1;
+
 /// This object is used to parse the ModuleTree and its use relationships from a directory
 ///
 /// Example:
 /// ```ignore
 /// let module_tree = ModuleTree::new("src/lib.rs");
 /// ```
+///
+/// With the feature `serde` the parsed facts can be saved and loaded again, e.g. as JSON:
+/// ```text
+/// {
+///   "schema_version": 1,
+///   "tree": [ModuleNode],                 // the modules, indexed by their position, the root first
+///   "possible_uses": { path: ObjectUse }  // the items by their fully qualified path
+/// }
+/// ```
+/// The item and dependency graphs are rebuilt when loading, trees of another schema version are rejected.
+/// The source lines are not saved, they are read again from the files of the modules when loading.
+/// Violations of modules whose files can not be read are reported without source snippets.
 #[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize),
+    serde(try_from = "ModuleTreeDocument")
+)]
 pub struct ModuleTree {
     tree: Vec<ModuleNode>,
     possible_uses: HashMap<String, ObjectUse>,
+    item_graph: ItemGraph,
+    dependency_graph: DependencyGraph,
+}
+
+/// Facts of a `ModuleTree` as serialized, the item graph is derived from them
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct ModuleTreeDocument {
+    schema_version: usize,
+    tree: Vec<ModuleNode>,
+    possible_uses: HashMap<String, ObjectUse>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ModuleTreeDocument> for ModuleTree {
+    type Error = String;
+
+    fn try_from(mut serialized: ModuleTreeDocument) -> Result<Self, Self::Error> {
+        if serialized.schema_version != MODULE_TREE_SCHEMA_VERSION {
+            return Err(format!(
+                "Unsupported schema version {}, expected {}",
+                serialized.schema_version, MODULE_TREE_SCHEMA_VERSION
+            ));
+        }
+        for node in serialized.tree.iter_mut() {
+            if let Ok(content) = fs::read_to_string(node.file_path()) {
+                node.set_line_index(LineIndex::new(&content));
+            }
+        }
+        let item_graph = ItemGraph::new(&serialized.tree, &serialized.possible_uses);
+        let dependency_graph = DependencyGraph::new(&serialized.tree, &serialized.possible_uses);
+        Ok(ModuleTree {
+            tree: serialized.tree,
+            possible_uses: serialized.possible_uses,
+            item_graph,
+            dependency_graph,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for ModuleTree {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ModuleTree", 3)?;
+        state.serialize_field("schema_version", &MODULE_TREE_SCHEMA_VERSION)?;
+        state.serialize_field("tree", &self.tree)?;
+        // Sorted, so the same code is always written the same way
+        state.serialize_field(
+            "possible_uses",
+            &self.possible_uses.iter().collect::<BTreeMap<_, _>>(),
+        )?;
+        state.end()
+    }
 }
 
 impl ModuleTree {
@@ -36,8 +124,11 @@ impl ModuleTree {
         let mut module_tree = ModuleTree {
             tree: vec![],
             possible_uses: HashMap::default(),
+            item_graph: ItemGraph::default(),
+            dependency_graph: DependencyGraph::default(),
         };
         parse_main_or_mod_file_into_tree(&mut module_tree.tree, path, 0, None, module_name);
+        module_tree.resolve_child_module_suppressions();
         module_tree.correct_fully_qualified_names();
         module_tree.replace_path_wildcard();
         module_tree.correct_fully_qualified_names();
@@ -46,9 +137,36 @@ impl ModuleTree {
         module_tree.filter_unused_uses();
         module_tree.filter_covered_implicit_uses();
         module_tree.construct_possible_use_map();
+        module_tree.item_graph = ItemGraph::new(&module_tree.tree, &module_tree.possible_uses);
+        module_tree.dependency_graph =
+            DependencyGraph::new(&module_tree.tree, &module_tree.possible_uses);
         module_tree
     }
 
+    /// Moves suppressions written in front of `mod name;` to the module they belong to
+    ///
+    /// The declaration may be nested in an inline module, so the children of every module of the file are considered.
+    fn resolve_child_module_suppressions(&mut self) {
+        for index in 0..self.tree.len() {
+            for i in (0..self.tree[index].suppressions.len()).rev() {
+                let child_index = match self.tree[index].suppressions[i].target() {
+                    SuppressionTarget::ChildModule(name) => self
+                        .tree
+                        .iter()
+                        .filter(|node| node.file_path() == self.tree[index].file_path())
+                        .flat_map(|node| node.children().iter().copied())
+                        .find(|child| self.tree[*child].module_name() == name),
+                    _ => None,
+                };
+                if let Some(child_index) = child_index {
+                    let mut suppression = self.tree[index].suppressions.remove(i);
+                    suppression.set_target(SuppressionTarget::Module);
+                    self.tree[child_index].suppressions.push(suppression);
+                }
+            }
+        }
+    }
+
     fn correct_fully_qualified_names(&mut self) {
         let fully_qualified_names: Vec<String> = self
             .tree
@@ -250,12 +368,15 @@ impl ModuleTree {
                             | ObjectType::Trait
                             | ObjectType::Enum
                             | ObjectType::Function => {
-                                self.tree[node_index].usable_objects.push(UsableObject::new(
-                                    use_obj.is_public(),
-                                    use_obj.object_type(),
-                                    format!("{}::{}", path, obj.object_name),
-                                    *use_obj.text_range(),
-                                ));
+                                self.tree[node_index].usable_objects.push(
+                                    UsableObject::new(
+                                        use_obj.is_public(),
+                                        use_obj.object_type(),
+                                        format!("{}::{}", path, obj.object_name),
+                                        *use_obj.text_range(),
+                                    )
+                                    .with_positions_of(&use_obj),
+                                );
                             }
                             _ => continue,
                         };
@@ -322,4 +443,97 @@ impl ModuleTree {
     pub fn possible_uses(&self) -> &HashMap<String, ObjectUse> {
         &self.possible_uses
     }
+
+    pub fn item_graph(&self) -> &ItemGraph {
+        &self.item_graph
+    }
+
+    pub fn dependency_graph(&self) -> &DependencyGraph {
+        &self.dependency_graph
+    }
+
+    /// Index of the module with the fully qualified path, e.g. `crate::parser::materials`
+    pub fn find_module(&self, module_path: &str) -> Option<usize> {
+        self.dependency_graph.find_module(module_path)
+    }
+
+    /// Modules the module uses, see `DependencyGraph::dependencies_of`
+    pub fn dependencies_of(&self, node_index: usize, transitive: bool) -> Vec<usize> {
+        self.dependency_graph
+            .dependencies_of(node_index, transitive)
+    }
+
+    /// Modules using the module, see `DependencyGraph::dependents_of`
+    pub fn dependents_of(&self, node_index: usize, transitive: bool) -> Vec<usize> {
+        self.dependency_graph.dependents_of(node_index, transitive)
+    }
+
+    /// Shortest chain of dependencies from one module to the other, see `DependencyGraph::path_between`
+    pub fn path_between(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        self.dependency_graph.path_between(from, to)
+    }
+
+    /// Items defined in the module, without the ones of its children
+    pub fn items_in(&self, node_index: usize) -> impl Iterator<Item = &ObjectUse> {
+        self.item_graph
+            .items_in_module(node_index)
+            .map(move |item_index| &self.item_graph.items()[item_index])
+    }
+
+    /// Read-only view on modules, items and dependencies for custom access rules
+    pub fn graph_view(&self) -> GraphView<'_> {
+        GraphView::new(
+            &self.tree,
+            &self.possible_uses,
+            &self.item_graph,
+            &self.dependency_graph,
+        )
+    }
+
+    /// Modules and their dependencies weighted by the number of uses, as selected by the options
+    pub fn module_graph(&self, options: &GraphOptions) -> ModuleGraph {
+        ModuleGraph::new(self.graph_view(), options)
+    }
+
+    /// The whole module tree as Graphviz DOT graph, see `ModuleGraph::to_dot`
+    pub fn to_dot(&self) -> String {
+        self.to_dot_with(&GraphOptions::default())
+    }
+
+    /// The module tree as Graphviz DOT graph as selected by the options, e.g. collapsed to layers
+    pub fn to_dot_with(&self, options: &GraphOptions) -> String {
+        self.module_graph(options).to_dot()
+    }
+
+    /// The module tree as Mermaid flowchart as selected by the options, see `ModuleGraph::to_mermaid`
+    pub fn to_mermaid(&self, options: &GraphOptions) -> String {
+        self.module_graph(options).to_mermaid()
+    }
+
+    /// The module tree as PlantUML component diagram as selected by the options, see `ModuleGraph::to_plantuml`
+    pub fn to_plantuml(&self, options: &GraphOptions) -> String {
+        self.module_graph(options).to_plantuml()
+    }
+
+    /// The module tree as Structurizr DSL workspace as selected by the options, see `ModuleGraph::to_structurizr`
+    pub fn to_structurizr(&self, options: &GraphOptions, name: &str) -> String {
+        self.module_graph(options).to_structurizr(name)
+    }
+
+    /// The module tree as GraphML document as selected by the options, see `ModuleGraph::to_graphml`
+    pub fn to_graphml(&self, options: &GraphOptions) -> String {
+        self.module_graph(options).to_graphml()
+    }
+
+    /// The module tree as GEXF document as selected by the options, see `ModuleGraph::to_gexf`
+    pub fn to_gexf(&self, options: &GraphOptions) -> String {
+        self.module_graph(options).to_gexf()
+    }
+}
+
+/// Allows to pass the tree wherever its nodes are expected, e.g. to `RuleViolation::render`
+impl AsRef<[ModuleNode]> for ModuleTree {
+    fn as_ref(&self) -> &[ModuleNode] {
+        &self.tree
+    }
 }
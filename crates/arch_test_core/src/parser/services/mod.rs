@@ -1,3 +1,4 @@
 pub use self::parser::parse_main_or_mod_file_into_tree;
 
 mod parser;
+mod suppressions;
@@ -3,8 +3,9 @@ use std::path::Path;
 
 use ra_ap_syntax::{SourceFile, SyntaxKind, SyntaxNode, SyntaxNodeChildren, TextRange, TextSize};
 
-use crate::parser::domain_values::{ObjectType, UsableObject};
+use crate::parser::domain_values::{LineIndex, ObjectType, UsableObject};
 use crate::parser::entities::ModuleNode;
+use crate::parser::services::suppressions::parse_suppressions;
 use crate::parser::utils::read_file_content;
 
 pub fn parse_main_or_mod_file_into_tree(
@@ -16,7 +17,9 @@ pub fn parse_main_or_mod_file_into_tree(
 ) {
     let mut module_references: Vec<(usize, String, Option<String>)> = Vec::new();
 
-    let result = SourceFile::parse(&read_file_content(file_path));
+    let content = read_file_content(file_path);
+    let result = SourceFile::parse(&content);
+    let current_index = tree.len();
     parse_syntax_node_tree(
         tree,
         result.syntax_node().children(),
@@ -26,6 +29,12 @@ pub fn parse_main_or_mod_file_into_tree(
         module_name,
         &mut module_references,
     );
+    tree[current_index].suppressions =
+        parse_suppressions(&result.syntax_node(), &content, file_path.to_str().unwrap());
+    let line_index = LineIndex::new(&content);
+    for node in tree.iter_mut().skip(current_index) {
+        node.set_line_index(line_index.clone());
+    }
 
     let dir_entries: Vec<DirEntry> = file_path
         .parent()
@@ -41,7 +50,7 @@ pub fn parse_main_or_mod_file_into_tree(
             } else {
                 format!(
                     "{}/{}",
-                    file_path.parent().unwrap().to_str().unwrap().to_string(),
+                    file_path.parent().unwrap().to_str().unwrap(),
                     sub_module_path.replace("\"", "").trim_start_matches("./")
                 )
             };
@@ -65,7 +74,7 @@ pub fn parse_main_or_mod_file_into_tree(
                 .ends_with(&sub_module)
         }) {
             if entry.path().is_dir() {
-                let path_str = format!("{}/mod.rs", entry.path().to_str().unwrap().to_string());
+                let path_str = format!("{}/mod.rs", entry.path().to_str().unwrap());
                 let mod_path = Path::new(&path_str);
                 if mod_path.exists() && mod_path.is_file() {
                     parse_main_or_mod_file_into_tree(
@@ -182,12 +191,15 @@ fn parse_file_rec(
         }
         SyntaxKind::STRUCT => {
             let mut is_pub = false;
+            let mut owner = None;
+            let first_object = usable_objects.len();
             for child in syntax_node.children() {
                 match child.kind() {
                     SyntaxKind::VISIBILITY => {
                         is_pub = true;
                     }
                     SyntaxKind::NAME => {
+                        owner = Some(child.to_string());
                         usable_objects.push(UsableObject::new(
                             is_pub,
                             ObjectType::Struct,
@@ -210,15 +222,19 @@ fn parse_file_rec(
                     }
                 }
             }
+            assign_owner(&mut usable_objects[first_object..], owner);
         }
         SyntaxKind::ENUM => {
             let mut is_pub = false;
+            let mut owner = None;
+            let first_object = usable_objects.len();
             for child in syntax_node.children() {
                 match child.kind() {
                     SyntaxKind::VISIBILITY => {
                         is_pub = true;
                     }
                     SyntaxKind::NAME => {
+                        owner = Some(child.to_string());
                         usable_objects.push(UsableObject::new(
                             is_pub,
                             ObjectType::Enum,
@@ -249,15 +265,19 @@ fn parse_file_rec(
                     _ => continue,
                 }
             }
+            assign_owner(&mut usable_objects[first_object..], owner);
         }
         SyntaxKind::FN | SyntaxKind::CLOSURE_EXPR | SyntaxKind::FN_PTR_TYPE => {
             let mut is_pub = false;
+            let mut owner = None;
+            let first_object = usable_objects.len();
             for child in syntax_node.children() {
                 match child.kind() {
                     SyntaxKind::VISIBILITY => {
                         is_pub = true;
                     }
                     SyntaxKind::NAME => {
+                        owner = Some(child.to_string());
                         usable_objects.push(UsableObject::new(
                             is_pub,
                             ObjectType::Function,
@@ -300,9 +320,10 @@ fn parse_file_rec(
                     }
                 }
             }
+            assign_owner(&mut usable_objects[first_object..], owner);
         }
         SyntaxKind::PATH_EXPR | SyntaxKind::TUPLE_STRUCT_PAT | SyntaxKind::PATH_PAT => {
-            for (impl_use_path, text_range) in parse_path_type(&syntax_node) {
+            for (impl_use_path, text_range) in parse_path_type(syntax_node) {
                 usable_objects.push(UsableObject::new(
                     false,
                     ObjectType::ImplicitUse,
@@ -313,12 +334,15 @@ fn parse_file_rec(
         }
         SyntaxKind::TRAIT => {
             let mut is_pub = false;
+            let mut owner = None;
+            let first_object = usable_objects.len();
             for child in syntax_node.children() {
                 match child.kind() {
                     SyntaxKind::VISIBILITY => {
                         is_pub = true;
                     }
                     SyntaxKind::NAME => {
+                        owner = Some(child.to_string());
                         usable_objects.push(UsableObject::new(
                             is_pub,
                             ObjectType::Trait,
@@ -339,12 +363,18 @@ fn parse_file_rec(
                     _ => continue,
                 }
             }
+            assign_owner(&mut usable_objects[first_object..], owner);
         }
         SyntaxKind::IMPL => {
+            // The implemented type is the last path type, e.g. `Bla` in `impl TraitName for Bla`
+            let mut owner = None;
+            let first_object = usable_objects.len();
             for child in syntax_node.children() {
                 match child.kind() {
                     SyntaxKind::PATH_TYPE => {
-                        for (impl_use_path, text_range) in parse_path_type(&child) {
+                        let paths = parse_path_type(&child);
+                        owner = paths.first().map(|(path, _)| path.clone());
+                        for (impl_use_path, text_range) in paths {
                             usable_objects.push(UsableObject::new(
                                 false,
                                 ObjectType::ImplicitUse,
@@ -366,6 +396,7 @@ fn parse_file_rec(
                     _ => continue,
                 }
             }
+            assign_owner(&mut usable_objects[first_object..], owner);
         }
         SyntaxKind::MODULE => {
             let mut path: Option<String> = None;
@@ -400,7 +431,7 @@ fn parse_file_rec(
             }
         }
         SyntaxKind::PARAM_LIST => {
-            for (impl_use_path, text_range) in parse_field_list(&syntax_node) {
+            for (impl_use_path, text_range) in parse_field_list(syntax_node) {
                 usable_objects.push(UsableObject::new(
                     false,
                     ObjectType::ImplicitUse,
@@ -413,7 +444,7 @@ fn parse_file_rec(
         | SyntaxKind::PATH_TYPE
         | SyntaxKind::TUPLE_PAT
         | SyntaxKind::SLICE_TYPE => {
-            for (impl_use_path, text_range) in parse_nested_tuple_type(&syntax_node) {
+            for (impl_use_path, text_range) in parse_nested_tuple_type(syntax_node) {
                 usable_objects.push(UsableObject::new(
                     false,
                     ObjectType::ImplicitUse,
@@ -471,12 +502,15 @@ fn parse_file_rec(
         }
         SyntaxKind::TYPE_ALIAS => {
             let mut is_pub = false;
+            let mut owner = None;
+            let first_object = usable_objects.len();
             for child in syntax_node.children() {
                 match child.kind() {
                     SyntaxKind::VISIBILITY => {
                         is_pub = true;
                     }
                     SyntaxKind::NAME => {
+                        owner = Some(child.to_string());
                         usable_objects.push(UsableObject::new(
                             is_pub,
                             ObjectType::TypeAlias,
@@ -497,9 +531,10 @@ fn parse_file_rec(
                     _ => continue,
                 }
             }
+            assign_owner(&mut usable_objects[first_object..], owner);
         }
         SyntaxKind::GENERIC_ARG_LIST => {
-            for (impl_use_path, text_range) in parse_generic_arg_list(&syntax_node) {
+            for (impl_use_path, text_range) in parse_generic_arg_list(syntax_node) {
                 usable_objects.push(UsableObject::new(
                     false,
                     ObjectType::ImplicitUse,
@@ -570,15 +605,11 @@ fn parse_file_rec(
             }
         }
         _ => {
-            println!(
-                "UNHANDLED EXPRESSION: {:?} => {}",
-                syntax_node,
-                syntax_node.to_string()
-            );
+            println!("UNHANDLED EXPRESSION: {:?} => {}", syntax_node, syntax_node);
             println!(
                 " => Parent: {:?} => {}",
                 syntax_node.parent().unwrap(),
-                syntax_node.parent().unwrap().to_string()
+                syntax_node.parent().unwrap()
             );
             return None;
         }
@@ -586,6 +617,17 @@ fn parse_file_rec(
     None
 }
 
+fn assign_owner(usable_objects: &mut [UsableObject], owner: Option<String>) {
+    if let Some(owner) = owner {
+        for obj in usable_objects
+            .iter_mut()
+            .filter(|obj| obj.object_type() == ObjectType::ImplicitUse && obj.owner().is_none())
+        {
+            obj.set_owner(owner.clone());
+        }
+    }
+}
+
 fn parse_use_paths(syntax_node: &SyntaxNode) -> (bool, Vec<(String, TextRange)>) {
     let mut visibility = false;
     let mut paths = Vec::new();
@@ -606,7 +648,7 @@ fn parse_use_paths(syntax_node: &SyntaxNode) -> (bool, Vec<(String, TextRange)>)
                 }
             }
             _ => {
-                println!("{:?} => {}", child, child.to_string());
+                println!("{:?} => {}", child, child);
                 unreachable!()
             }
         }
@@ -669,11 +711,7 @@ fn parse_path_type(syntax_node: &SyntaxNode) -> Vec<(String, TextRange)> {
                                             ));
                                         } else {
                                             obj_uses.push((
-                                                format!(
-                                                    "{}::{}",
-                                                    current_path,
-                                                    p_segment_child.to_string()
-                                                ),
+                                                format!("{}::{}", current_path, p_segment_child),
                                                 p_segment_child.text_range(),
                                             ));
                                         }
@@ -766,10 +804,10 @@ fn parse_nested_tuple_type(syntax_node: &SyntaxNode) -> Vec<(String, TextRange)>
             }
         }
         SyntaxKind::PATH_TYPE | SyntaxKind::TUPLE_STRUCT_PAT | SyntaxKind::RECORD_PAT | SyntaxKind::PATH_PAT | SyntaxKind::PATH_EXPR => {
-            result.append(&mut parse_path_type(&syntax_node));
+            result.append(&mut parse_path_type(syntax_node));
         }
         _ => {
-            println!("{:?} => {}", syntax_node, syntax_node.to_string());
+            println!("{:?} => {}", syntax_node, syntax_node);
             unreachable!()
         }
     }
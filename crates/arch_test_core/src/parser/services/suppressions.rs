@@ -0,0 +1,184 @@
+use ra_ap_syntax::{NodeOrToken, SyntaxKind, SyntaxNode, TextRange, TextSize};
+
+use crate::parser::domain_values::{Suppression, SuppressionTarget};
+
+const COMMENT_MARKER: &str = "archtest:allow(";
+const ATTRIBUTE_MARKER: &str = "allow_arch(";
+
+/// Extracts `// archtest:allow(...)` comments and `#[allow_arch(...)]` attributes of a file
+pub fn parse_suppressions(
+    source_file: &SyntaxNode,
+    content: &str,
+    file_path: &str,
+) -> Vec<Suppression> {
+    let mut suppressions = Vec::new();
+    for element in source_file.descendants_with_tokens() {
+        let (text, text_range, parent) = match element {
+            NodeOrToken::Token(token) if token.kind() == SyntaxKind::COMMENT => {
+                match token.parent() {
+                    Some(parent) => (token.text().to_string(), token.text_range(), parent),
+                    None => continue,
+                }
+            }
+            NodeOrToken::Node(node) if node.kind() == SyntaxKind::ATTR => match node.parent() {
+                Some(parent) => (node.to_string(), node.text_range(), parent),
+                None => continue,
+            },
+            _ => continue,
+        };
+        if let Some((rules, reason)) = marker_arguments(&text) {
+            let is_inner = text.starts_with("//!") || text.starts_with("#!");
+            suppressions.push(Suppression::new(
+                rules,
+                reason,
+                file_path.to_owned(),
+                content[..usize::from(text_range.start())]
+                    .matches('\n')
+                    .count()
+                    + 1,
+                target_of(&parent, is_inner, text_range, content),
+            ));
+        }
+    }
+    suppressions
+}
+
+fn target_of(
+    parent: &SyntaxNode,
+    is_inner: bool,
+    text_range: TextRange,
+    content: &str,
+) -> SuppressionTarget {
+    // A trailing comment is attached to the next item by the parser, but refers to the code before it
+    if !is_inner && follows_code(content, text_range) {
+        return SuppressionTarget::Range(line_range(content, text_range));
+    }
+    match parent.kind() {
+        SyntaxKind::SOURCE_FILE if is_inner => SuppressionTarget::Module,
+        SyntaxKind::ITEM_LIST if is_inner => match parent.parent() {
+            Some(module) if module.kind() == SyntaxKind::MODULE => {
+                SuppressionTarget::Range(module.text_range())
+            }
+            _ => SuppressionTarget::Range(parent.text_range()),
+        },
+        SyntaxKind::MODULE if !is_inner => {
+            let has_item_list = parent
+                .children()
+                .any(|child| child.kind() == SyntaxKind::ITEM_LIST);
+            match parent
+                .children()
+                .find(|child| child.kind() == SyntaxKind::NAME)
+            {
+                Some(name) if !has_item_list => SuppressionTarget::ChildModule(name.to_string()),
+                _ => SuppressionTarget::Range(parent.text_range()),
+            }
+        }
+        SyntaxKind::USE
+        | SyntaxKind::STRUCT
+        | SyntaxKind::ENUM
+        | SyntaxKind::FN
+        | SyntaxKind::TRAIT
+        | SyntaxKind::IMPL
+        | SyntaxKind::TYPE_ALIAS
+        | SyntaxKind::CONST
+        | SyntaxKind::STATIC
+        | SyntaxKind::UNION
+        | SyntaxKind::MACRO_RULES
+            if parent.text_range().start() == text_range.start() =>
+        {
+            SuppressionTarget::Range(parent.text_range())
+        }
+        _ => SuppressionTarget::Range(line_range(content, text_range)),
+    }
+}
+
+/// Whether the marker is preceded by code in the same line
+fn follows_code(content: &str, text_range: TextRange) -> bool {
+    let start = usize::from(text_range.start());
+    let line_start = content[..start].rfind('\n').map_or(0, |index| index + 1);
+    !content[line_start..start].trim().is_empty()
+}
+
+/// Range of the whole line the marker is written in
+fn line_range(content: &str, text_range: TextRange) -> TextRange {
+    let start = usize::from(text_range.start());
+    let line_start = content[..start].rfind('\n').map_or(0, |index| index + 1);
+    let line_end = content[start..]
+        .find('\n')
+        .map_or(content.len(), |index| start + index);
+    TextRange::new(
+        TextSize::from(line_start as u32),
+        TextSize::from(line_end as u32),
+    )
+}
+
+/// Arguments of a comment starting with the marker or of an `allow_arch` attribute, possibly nested in `cfg_attr`
+fn marker_arguments(text: &str) -> Option<(Vec<String>, Option<String>)> {
+    if text.starts_with("//") {
+        let comment = text
+            .trim_start_matches("//")
+            .trim_start_matches('!')
+            .trim_start();
+        if comment.starts_with(COMMENT_MARKER) {
+            return parse_arguments(comment, COMMENT_MARKER);
+        }
+        return None;
+    }
+    let attribute = text
+        .trim_start_matches('#')
+        .trim_start_matches('!')
+        .trim_start_matches('[')
+        .trim_start();
+    if attribute.starts_with(ATTRIBUTE_MARKER) || attribute.starts_with("cfg_attr(") {
+        return parse_arguments(attribute, ATTRIBUTE_MARKER);
+    }
+    None
+}
+
+/// Rules and reason of `Rule1, Rule2, reason = "..."`, rules and reason may be given in any order
+fn parse_arguments(text: &str, marker: &str) -> Option<(Vec<String>, Option<String>)> {
+    let start = text.find(marker)? + marker.len();
+    let mut arguments = vec![String::new()];
+    let mut in_string = false;
+    let mut closed = false;
+    for character in text[start..].chars() {
+        match character {
+            '"' => in_string = !in_string,
+            ')' if !in_string => {
+                closed = true;
+                break;
+            }
+            ',' if !in_string => {
+                arguments.push(String::new());
+                continue;
+            }
+            _ => {}
+        }
+        arguments.last_mut().unwrap().push(character);
+    }
+    if !closed {
+        return None;
+    }
+
+    let mut rules = Vec::new();
+    let mut reason = None;
+    for argument in arguments.iter().map(|argument| argument.trim()) {
+        if argument.is_empty() {
+            continue;
+        }
+        match argument.split_once('=') {
+            Some((key, value)) if key.trim() == "reason" => {
+                let value = value.trim().trim_matches('"').trim();
+                if !value.is_empty() {
+                    reason = Some(value.to_owned());
+                }
+            }
+            _ => rules.push(argument.to_owned()),
+        }
+    }
+    if rules.is_empty() {
+        None
+    } else {
+        Some((rules, reason))
+    }
+}
@@ -1,3 +1,4 @@
+mod module_graph;
 mod module_node;
 mod module_tree;
 mod parser;
@@ -0,0 +1 @@
+pub struct Test2;
\ No newline at end of file
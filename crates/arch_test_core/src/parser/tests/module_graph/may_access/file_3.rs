@@ -0,0 +1,5 @@
+use crate::file_2::Test2;
+
+fn fun_3() {
+    let a = Test2;
+}
\ No newline at end of file
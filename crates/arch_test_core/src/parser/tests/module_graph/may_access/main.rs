@@ -0,0 +1,3 @@
+mod file_1;
+mod file_2;
+mod file_3;
\ No newline at end of file
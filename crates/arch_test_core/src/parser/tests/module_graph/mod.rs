@@ -0,0 +1,141 @@
+use crate::parser::domain_values::{GraphOptions, ObjectType};
+use crate::parser::entities::ModuleGraph;
+use crate::ModuleTree;
+
+const PROJECT: &str = "src/parser/tests/module_graph/may_access/main.rs";
+
+/// `crate::file_1` uses `crate::file_2` against a rule, `crate::file_3` uses it as well
+fn options() -> GraphOptions {
+    GraphOptions::default().with_highlighted_use(1, 2)
+}
+
+fn layered(module_tree: &ModuleTree) -> ModuleGraph {
+    module_tree.module_graph(&options().with_layers(vec!["file_1".to_owned(), "file_2".to_owned()]))
+}
+
+#[test]
+fn dot_graph() {
+    let module_tree = ModuleTree::new(PROJECT);
+    let dot = module_tree.to_dot_with(&options());
+    assert!(dot.starts_with("digraph architecture {\n"));
+    assert!(dot.contains("    subgraph cluster_m0 {\n        label=\"crate\";\n"));
+    assert!(dot.contains("        m1 [label=\"file_1\"];\n"));
+    assert!(dot
+        .contains("    m1 -> m2 [weight=1, label=\"1\", color=red, fontcolor=red, penwidth=2];\n"));
+    assert!(dot.contains("    m3 -> m2 [weight=1, label=\"1\"];\n"));
+    assert_eq!(dot, module_tree.to_dot_with(&options()));
+    assert!(!module_tree.to_dot().contains("color=red"));
+}
+
+#[test]
+fn collapsed_graph() {
+    let module_tree = ModuleTree::new(PROJECT);
+    let layers = module_tree.module_graph(
+        &options()
+            .with_layers(vec!["file_1".to_owned(), "file_2".to_owned()])
+            .with_collapsed_layers(),
+    );
+    assert_eq!(layers.nodes().len(), 2);
+    assert_eq!(layers.nodes()[0].name(), "file_1");
+    assert_eq!(layers.edges().len(), 1);
+    assert!(layers.edges()[0].highlighted());
+
+    let root_only = module_tree.module_graph(&GraphOptions::default().with_max_depth(0));
+    assert_eq!(root_only.nodes().len(), 1);
+    assert!(root_only.edges().is_empty());
+
+    let subtree =
+        module_tree.module_graph(&GraphOptions::default().with_subtree("crate::file_2".to_owned()));
+    assert_eq!(subtree.nodes().len(), 1);
+    assert_eq!(subtree.nodes()[0].path(), "crate::file_2");
+    assert_eq!(subtree.nodes()[0].parent(), None);
+}
+
+#[test]
+fn neighbourhood_graph() {
+    let module_tree = ModuleTree::new(PROJECT);
+    let neighbourhood = module_tree.module_graph(
+        &GraphOptions::default()
+            .with_layers(vec!["file_1".to_owned(), "file_2".to_owned()])
+            .with_neighbourhood_of("file_1".to_owned()),
+    );
+    assert!(neighbourhood
+        .nodes()
+        .iter()
+        .all(|node| node.path() != "crate::file_3"));
+    assert_eq!(neighbourhood.edges().len(), 1);
+}
+
+#[test]
+fn mermaid_graph() {
+    let module_tree = ModuleTree::new(PROJECT);
+    let mermaid = layered(&module_tree).to_mermaid();
+    assert!(mermaid.starts_with("flowchart LR\n"));
+    assert!(mermaid
+        .contains("    subgraph layer_0[\"file_1\"]\n        m1[\"crate::file_1\"]\n    end\n"));
+    assert!(mermaid.contains("    m1 -->|1| m2\n"));
+    assert!(mermaid.contains("    linkStyle 0 stroke:red,stroke-width:2px\n"));
+}
+
+#[test]
+fn plantuml_graph() {
+    let module_tree = ModuleTree::new(PROJECT);
+    let plantuml = layered(&module_tree).to_plantuml();
+    assert!(plantuml.starts_with("@startuml\n"));
+    assert!(plantuml.contains("package \"file_2\" {\n  [crate::file_2] as m2\n}\n"));
+    assert!(plantuml.contains("m1 -[#red,bold]-> m2 : 1\n"));
+    assert!(plantuml.ends_with("@enduml\n"));
+    assert!(module_tree
+        .to_plantuml(&GraphOptions::default())
+        .contains("package \"crate\" {\n  [crate] as m0\n"));
+}
+
+#[test]
+fn graphml_graph() {
+    let module_tree = ModuleTree::new(PROJECT);
+    let graphml = layered(&module_tree).to_graphml();
+    assert!(graphml.contains(
+        "  <key id=\"item_count\" for=\"node\" attr.name=\"item_count\" attr.type=\"int\"/>\n"
+    ));
+    assert!(graphml.contains("    <node id=\"m1\">\n      <data key=\"name\">file_1</data>\n      <data key=\"path\">crate::file_1</data>\n      <data key=\"parent\">crate</data>\n"));
+    assert!(graphml.contains("      <data key=\"level\">1</data>\n      <data key=\"layer\">file_1</data>\n      <data key=\"item_count\">1</data>\n"));
+    assert!(graphml.contains("    <edge id=\"e0\" source=\"m1\" target=\"m2\">\n      <data key=\"weight\">1</data>\n      <data key=\"kinds\">Struct</data>\n      <data key=\"highlighted\">true</data>\n"));
+}
+
+#[test]
+fn gexf_graph() {
+    let module_tree = ModuleTree::new(PROJECT);
+    let gexf = layered(&module_tree).to_gexf();
+    assert!(gexf.contains("<gexf xmlns=\"http://gexf.net/1.3\" version=\"1.3\">\n"));
+    assert!(gexf.contains("      <attribute id=\"level\" title=\"level\" type=\"integer\"/>\n"));
+    assert!(gexf.contains(
+        "      <attribute id=\"highlighted\" title=\"highlighted\" type=\"boolean\"/>\n"
+    ));
+    assert!(!gexf.contains("type=\"int\""));
+    assert!(gexf.contains("      <node id=\"m2\" label=\"file_2\" pid=\"m0\">\n"));
+    assert!(gexf.contains("      <edge id=\"e1\" source=\"m3\" target=\"m2\" weight=\"1\">\n"));
+}
+
+#[test]
+fn structurizr_graph() {
+    let module_tree = ModuleTree::new(PROJECT);
+    assert!(layered(&module_tree).to_structurizr("may_access").contains(
+        "                group \"file_2\" {\n                    m2 = component \"crate::file_2\""
+    ));
+}
+
+#[test]
+fn item_graph() {
+    let module_tree = ModuleTree::new(PROJECT);
+    let items = module_tree.module_graph(&options().with_items());
+    assert_eq!(items.nodes().len(), 3);
+    assert_eq!(items.nodes()[1].path(), "crate::file_2::Test2");
+    assert_eq!(items.nodes()[1].kind(), Some(ObjectType::Struct));
+    assert!(items.nodes()[1].file().unwrap().ends_with("file_2.rs"));
+    assert_eq!(items.edges().len(), 2);
+    assert!(items.edges()[0].highlighted());
+    assert_eq!(items.edges()[1].kinds(), &vec![ObjectType::Struct]);
+    assert!(items
+        .to_gexf()
+        .contains("<attvalue for=\"kind\" value=\"Function\"/>"));
+}
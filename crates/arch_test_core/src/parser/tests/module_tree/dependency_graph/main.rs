@@ -0,0 +1,9 @@
+mod materials;
+mod services;
+mod values;
+
+use crate::services::Service;
+
+fn main() {
+    let service = Service::new();
+}
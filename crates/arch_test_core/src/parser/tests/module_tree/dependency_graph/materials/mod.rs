@@ -0,0 +1,9 @@
+pub mod stock;
+
+pub struct Material {
+    weight: u32,
+}
+
+pub fn create() -> Material {
+    Material { weight: 1 }
+}
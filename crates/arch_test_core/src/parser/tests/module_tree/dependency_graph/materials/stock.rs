@@ -0,0 +1,5 @@
+use crate::values::Value;
+
+pub struct Stock {
+    value: Value,
+}
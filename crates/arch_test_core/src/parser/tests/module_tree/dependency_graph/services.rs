@@ -0,0 +1,7 @@
+use crate::materials::stock::Stock;
+use crate::materials::Material;
+
+pub struct Service {
+    material: Material,
+    stock: Stock,
+}
@@ -0,0 +1,11 @@
+pub struct Test1;
+
+impl Test1 {
+    pub fn new() -> Self {
+        Test1
+    }
+}
+
+pub fn helper() -> Test1 {
+    Test1
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+mod file_1;
+
+use crate::file_1::Test1;
+
+pub struct Test2 {
+    test_1: Test1,
+}
+
+pub fn main() {
+    let a = Test1::new();
+}
\ No newline at end of file
@@ -130,3 +130,169 @@ fn filter_unused_uses() {
         "crate::file_1::Test1".to_owned()
     );
 }
+
+#[test]
+fn item_graph() {
+    let module_tree = ModuleTree::new("src/parser/tests/module_tree/item_graph/main.rs");
+    let item_graph = module_tree.item_graph();
+
+    let items: Vec<&String> = item_graph
+        .items()
+        .iter()
+        .map(|item| item.full_module_path())
+        .collect();
+    assert_eq!(
+        items,
+        vec![
+            "crate::Test2",
+            "crate::file_1::Test1",
+            "crate::file_1::helper",
+            "crate::main"
+        ]
+    );
+    assert_eq!(item_graph.dependencies().len(), 3);
+
+    let test_1 = item_graph.find_item("crate::file_1::Test1").unwrap();
+    let main = item_graph.find_item("crate::main").unwrap();
+    assert_eq!(item_graph.dependents_of(test_1).count(), 3);
+    assert_eq!(item_graph.dependencies_of(test_1).count(), 0);
+    assert_eq!(
+        item_graph
+            .dependencies_of(main)
+            .map(|dependency| dependency.used_item())
+            .collect::<Vec<usize>>(),
+        vec![test_1]
+    );
+    assert_eq!(
+        item_graph.items_in_module(1).collect::<Vec<usize>>(),
+        vec![
+            test_1,
+            item_graph.find_item("crate::file_1::helper").unwrap()
+        ]
+    );
+}
+
+#[test]
+fn graph_view() {
+    let module_tree = ModuleTree::new("src/parser/tests/module_tree/item_graph/main.rs");
+    let graph = module_tree.graph_view();
+
+    assert_eq!(graph.modules().count(), 2);
+    assert_eq!(graph.items().count(), 4);
+    assert_eq!(graph.item_dependencies().count(), 3);
+    assert_eq!(graph.module_path(1), "crate::file_1");
+    assert!(graph.module_in_layer(1, "file_1"));
+    assert!(graph.module_in_layer(1, "crate"));
+    assert!(!graph.module_in_layer(0, "file_1"));
+    assert_eq!(graph.modules_in_layer("file_1").count(), 1);
+    assert!(graph.item_in_layer(
+        graph
+            .item_graph()
+            .find_item("crate::file_1::helper")
+            .unwrap(),
+        "file_1"
+    ));
+    assert!(graph.dependencies().count() > 0);
+    assert!(graph.dependencies().all(|use_relation| {
+        use_relation.using_object().node_index() == 0
+            && use_relation.used_object().node_index() == 1
+    }));
+    assert_eq!(graph.dependencies_of_module(1).count(), 0);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serialized_module_tree() {
+    let module_tree = ModuleTree::new("src/parser/tests/module_tree/item_graph/main.rs");
+    let json = serde_json::to_value(&module_tree).unwrap();
+    assert_eq!(json["schema_version"], crate::MODULE_TREE_SCHEMA_VERSION);
+    assert_eq!(json["tree"][0]["module_name"], "crate");
+    assert_eq!(json["possible_uses"]["crate::Test2"]["node_index"], 0);
+
+    let loaded: ModuleTree = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&loaded).unwrap(), json);
+    assert_eq!(loaded.tree().len(), module_tree.tree().len());
+    assert_eq!(
+        loaded.item_graph().dependencies().len(),
+        module_tree.item_graph().dependencies().len()
+    );
+    assert_eq!(
+        loaded.tree()[1].usable_objects[0].text_range(),
+        module_tree.tree()[1].usable_objects[0].text_range()
+    );
+    assert!(json["tree"][0].get("line_index").is_none());
+    assert_eq!(
+        loaded.tree()[0].line_index().line(1),
+        module_tree.tree()[0].line_index().line(1)
+    );
+    assert!(loaded.tree()[0].line_index().line_count() > 0);
+
+    let mut moved = json.clone();
+    moved["tree"][0]["file_path"] = serde_json::json!("src/parser/tests/module_tree/missing.rs");
+    let loaded: ModuleTree = serde_json::from_value(moved).unwrap();
+    assert_eq!(loaded.tree()[0].line_index().line_count(), 0);
+    assert_eq!(
+        loaded.tree()[0].usable_objects[0].start_position(),
+        module_tree.tree()[0].usable_objects[0].start_position()
+    );
+
+    let mut outdated = json;
+    outdated["schema_version"] = serde_json::json!(0);
+    let error = serde_json::from_value::<ModuleTree>(outdated).unwrap_err();
+    assert!(error.to_string().contains("Unsupported schema version 0"));
+}
+
+#[test]
+fn dependency_graph() {
+    let module_tree = ModuleTree::new("src/parser/tests/module_tree/dependency_graph/main.rs");
+    let root = module_tree.find_module("crate").unwrap();
+    let materials = module_tree.find_module("crate::materials").unwrap();
+    let stock = module_tree.find_module("crate::materials::stock").unwrap();
+    let services = module_tree.find_module("crate::services").unwrap();
+    let values = module_tree.find_module("crate::values").unwrap();
+    assert_eq!(module_tree.find_module("crate::unknown"), None);
+
+    assert_eq!(
+        module_tree.dependencies_of(services, false),
+        vec![materials, stock]
+    );
+    assert_eq!(
+        module_tree.dependencies_of(services, true),
+        vec![materials, stock, values]
+    );
+    assert_eq!(module_tree.dependencies_of(materials, false), vec![values]);
+    assert!(module_tree.dependencies_of(root, true).is_empty());
+    assert_eq!(module_tree.dependents_of(materials, false), vec![services]);
+    assert_eq!(
+        module_tree.dependents_of(values, true),
+        vec![root, stock, services]
+    );
+    assert!(module_tree.dependents_of(root, false).is_empty());
+
+    assert_eq!(
+        module_tree.path_between(services, values),
+        Some(vec![services, stock, values])
+    );
+    assert_eq!(
+        module_tree.path_between(materials, values),
+        Some(vec![stock, values])
+    );
+    assert_eq!(module_tree.path_between(values, services), None);
+    assert_eq!(module_tree.path_between(values, values), Some(vec![values]));
+
+    let items: Vec<&String> = module_tree
+        .items_in(materials)
+        .map(|item| item.full_module_path())
+        .collect();
+    assert_eq!(
+        items,
+        vec!["crate::materials::Material", "crate::materials::create"]
+    );
+    assert_eq!(
+        module_tree
+            .graph_view()
+            .dependency_graph()
+            .dependents_of(materials, false),
+        vec![services]
+    );
+}
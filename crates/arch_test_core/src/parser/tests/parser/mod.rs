@@ -4,6 +4,7 @@ mod functions;
 mod macros;
 mod modules;
 mod struct_stmt;
+mod suppressions;
 mod traits;
 mod types;
 mod use_stmt;
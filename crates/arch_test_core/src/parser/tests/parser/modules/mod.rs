@@ -53,7 +53,7 @@ fn inner_modules() {
 
     assert_eq!(node_tree[2].level(), 2);
     assert_eq!(node_tree[2].module_name(), "mod2");
-    assert_eq!(node_tree[2].children(), &[]);
+    assert_eq!(node_tree[2].children(), &Vec::<usize>::new());
     assert_eq!(node_tree[2].usable_objects.len(), 1);
     assert_eq!(
         node_tree[2].usable_objects[0].object_type(),
@@ -66,7 +66,7 @@ fn inner_modules() {
 
     assert_eq!(node_tree[3].level(), 1);
     assert_eq!(node_tree[3].module_name(), "mod3");
-    assert_eq!(node_tree[3].children(), &[]);
+    assert_eq!(node_tree[3].children(), &Vec::<usize>::new());
     assert_eq!(node_tree[3].usable_objects.len(), 1);
     assert_eq!(
         node_tree[3].usable_objects[0].object_type(),
@@ -102,7 +102,7 @@ fn nested_folders_and_files() {
 
     assert_eq!(node_tree[3].level(), 2);
     assert_eq!(node_tree[3].module_name(), "file_2");
-    assert_eq!(node_tree[3].children(), &[]);
+    assert_eq!(node_tree[3].children(), &Vec::<usize>::new());
     assert_eq!(node_tree[3].usable_objects.len(), 0);
 
     assert_eq!(node_tree[4].level(), 1);
@@ -117,17 +117,17 @@ fn nested_folders_and_files() {
 
     assert_eq!(node_tree[6].level(), 3);
     assert_eq!(node_tree[6].module_name(), "file_4");
-    assert_eq!(node_tree[6].children(), &[]);
+    assert_eq!(node_tree[6].children(), &Vec::<usize>::new());
     assert_eq!(node_tree[6].usable_objects.len(), 0);
 
     assert_eq!(node_tree[7].level(), 2);
     assert_eq!(node_tree[7].module_name(), "file_3");
-    assert_eq!(node_tree[7].children(), &[]);
+    assert_eq!(node_tree[7].children(), &Vec::<usize>::new());
     assert_eq!(node_tree[7].usable_objects.len(), 0);
 
     assert_eq!(node_tree[8].level(), 2);
     assert_eq!(node_tree[8].module_name(), "file_5");
-    assert_eq!(node_tree[8].children(), &[]);
+    assert_eq!(node_tree[8].children(), &Vec::<usize>::new());
     assert_eq!(node_tree[8].usable_objects.len(), 0);
 }
 
@@ -0,0 +1,62 @@
+use crate::parser::domain_values::SuppressionTarget;
+use crate::parser::services::parse_main_or_mod_file_into_tree;
+use ra_ap_syntax::{TextRange, TextSize};
+use std::path::Path;
+
+#[test]
+fn targets() {
+    let mut node_tree = Vec::new();
+    let path = Path::new("src/parser/tests/parser/suppressions/targets.rs");
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned());
+
+    let suppressions = node_tree[0].suppressions();
+    assert_eq!(suppressions.len(), 5);
+
+    assert_eq!(
+        suppressions[0].rules(),
+        &vec!["NoModuleCyclicDependencies".to_owned()]
+    );
+    assert_eq!(suppressions[0].target(), &SuppressionTarget::Module);
+    assert_eq!(suppressions[0].line(), 1);
+
+    assert_eq!(suppressions[1].rules(), &vec!["MayNotAccess".to_owned()]);
+    assert_eq!(suppressions[1].reason(), Some(&"legacy import".to_owned()));
+    assert_eq!(
+        suppressions[1].target(),
+        &SuppressionTarget::Range(TextRange::new(TextSize::from(47), TextSize::from(129)))
+    );
+
+    assert_eq!(
+        suppressions[2].rules(),
+        &vec!["MayOnlyAccess".to_owned(), "NoParentAccess".to_owned()]
+    );
+    assert_eq!(
+        suppressions[2].reason(),
+        Some(&"adapter, see #12".to_owned())
+    );
+    assert_eq!(suppressions[2].line(), 5);
+
+    assert_eq!(suppressions[3].rules(), &vec!["*".to_owned()]);
+    assert_eq!(
+        suppressions[3].target(),
+        &SuppressionTarget::ChildModule("third".to_owned())
+    );
+
+    assert_eq!(suppressions[4].rules(), &vec!["NoParentAccess".to_owned()]);
+    assert_eq!(suppressions[4].line(), 14);
+}
+
+#[test]
+fn trailing_comment_before_next_item() {
+    let mut node_tree = Vec::new();
+    let path = Path::new("src/parser/tests/parser/suppressions/trailing.rs");
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned());
+
+    let suppressions = node_tree[0].suppressions();
+    assert_eq!(suppressions.len(), 1);
+    assert_eq!(suppressions[0].line(), 1);
+    assert_eq!(
+        suppressions[0].target(),
+        &SuppressionTarget::Range(TextRange::new(TextSize::from(0), TextSize::from(58)))
+    );
+}
@@ -0,0 +1,16 @@
+//! archtest:allow(NoModuleCyclicDependencies)
+use crate::first::First; // archtest:allow(MayNotAccess, reason = "legacy import")
+
+/// Mentions archtest:allow(MayNotAccess) only in its documentation
+#[cfg_attr(archtest, allow_arch(MayOnlyAccess, NoParentAccess, reason = "adapter, see #12"))]
+pub struct Second {
+    first: First,
+}
+
+// archtest:allow(*)
+mod third;
+
+mod fourth {
+    #![allow_arch(NoParentAccess)]
+    pub struct Fourth;
+}
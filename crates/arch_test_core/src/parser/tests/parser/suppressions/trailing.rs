@@ -0,0 +1,2 @@
+use crate::first::First; // archtest:allow(NoParentAccess)
+use crate::second::Second;
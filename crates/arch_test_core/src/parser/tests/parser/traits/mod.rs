@@ -65,3 +65,13 @@ fn impl_trait() {
     assert_eq!(node_tree[0].usable_objects[2].object_name, "b".to_owned());
     assert_eq!(node_tree[0].usable_objects[3].object_name, "c".to_owned());
 }
+
+#[test]
+fn impl_trait_owner() {
+    let mut node_tree = Vec::new();
+    let path = Path::new("src/parser/tests/parser/traits/impl_trait.rs");
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned());
+    for obj in node_tree[0].usable_objects.iter() {
+        assert_eq!(obj.owner(), Some(&"Bla".to_owned()));
+    }
+}
@@ -1,6 +1,8 @@
 use std::path::Path;
 
-use crate::parser::domain_values::ObjectType;
+use ra_ap_syntax::TextSize;
+
+use crate::parser::domain_values::{LineIndex, ObjectType};
 use crate::parser::services::parse_main_or_mod_file_into_tree;
 
 #[test]
@@ -63,3 +65,42 @@ fn single_stmt() {
 
     assert_eq!(node_tree[0].usable_objects[0].object_name, "a".to_owned());
 }
+
+#[test]
+fn positions() {
+    let mut node_tree = Vec::new();
+    let path = Path::new("src/parser/tests/parser/use_stmt/positions.rs");
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned());
+
+    let usable_objects = &node_tree[0].usable_objects;
+    let use_object = usable_objects
+        .iter()
+        .find(|obj| obj.object_type() == ObjectType::Use)
+        .unwrap();
+    assert_eq!(use_object.start_position(), (2, 5));
+    assert_eq!(use_object.end_position(), (2, 18));
+    let struct_object = usable_objects
+        .iter()
+        .find(|obj| obj.object_type() == ObjectType::Struct)
+        .unwrap();
+    assert_eq!(struct_object.start_position(), (4, 12));
+    assert_eq!(struct_object.end_position(), (4, 17));
+
+    let line_index = node_tree[0].line_index();
+    assert_eq!(line_index.line(2), Some(&"use first::second;".to_owned()));
+    assert_eq!(line_index.line(3), Some(&String::new()));
+    assert_eq!(line_index.line(0), None);
+}
+
+#[test]
+fn line_index() {
+    let line_index = LineIndex::new("fn wambo() {\r\n    \"äöü\";\n}");
+    assert_eq!(line_index.line_count(), 3);
+    assert_eq!(line_index.position(TextSize::from(0)), (1, 1));
+    assert_eq!(line_index.position(TextSize::from(12)), (1, 13));
+    assert_eq!(line_index.position(TextSize::from(13)), (1, 13));
+    assert_eq!(line_index.position(TextSize::from(14)), (2, 1));
+    assert_eq!(line_index.position(TextSize::from(25)), (2, 9));
+    assert_eq!(line_index.position(TextSize::from(28)), (3, 1));
+    assert_eq!(line_index.line(1), Some(&"fn wambo() {".to_owned()));
+}
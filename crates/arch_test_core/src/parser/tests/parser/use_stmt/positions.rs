@@ -0,0 +1,4 @@
+// Lines end with CRLF
+use first::second;
+
+pub struct Wambo;
@@ -1,3 +1,5 @@
 pub use self::read_file_content::read_file_content;
 
 mod read_file_content;
+#[cfg(feature = "serde")]
+pub mod serde_text_range;
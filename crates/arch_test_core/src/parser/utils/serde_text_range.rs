@@ -0,0 +1,18 @@
+use ra_ap_syntax::{TextRange, TextSize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Writes a `TextRange` as `[start, end]` byte offsets, used with `#[serde(with = "...")]`
+pub fn serialize<S: Serializer>(text_range: &TextRange, serializer: S) -> Result<S::Ok, S::Error> {
+    [u32::from(text_range.start()), u32::from(text_range.end())].serialize(serializer)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<TextRange, D::Error> {
+    let [start, end] = <[u32; 2]>::deserialize(deserializer)?;
+    if start > end {
+        return Err(serde::de::Error::custom(format!(
+            "text range starts at {} after its end {}",
+            start, end
+        )));
+    }
+    Ok(TextRange::new(TextSize::from(start), TextSize::from(end)))
+}
@@ -3,6 +3,10 @@ pub enum AccessRule {
     NoParentAccess,
     NoModuleCyclicDependencies,
     NoLayerCyclicDependencies,
+    NoItemCyclicDependencies {
+        #[serde(default)]
+        scope: ItemCycleScope,
+    },
     MayOnlyAccess {
         accessor: String,
         accessed: Vec<String>,
@@ -23,4 +27,19 @@ pub enum AccessRule {
         accessed: String,
         when_same_parent: bool,
     },
+    AllOf(Vec<AccessRule>),
+    AnyOf(Vec<AccessRule>),
+    Not(Box<AccessRule>),
+    Within {
+        scope: String,
+        rule: Box<AccessRule>,
+    },
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub enum ItemCycleScope {
+    #[default]
+    Crate,
+    WithinLayer(String),
+    AcrossLayers(Vec<String>),
 }
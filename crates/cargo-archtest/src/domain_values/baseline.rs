@@ -0,0 +1,76 @@
+use arch_test_core::ViolationFingerprint;
+
+/// Violations accepted by the project, written by `cargo archtest --write-baseline`
+///
+/// Only violations that are not part of the baseline fail the check.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Baseline {
+    pub version: u32,
+    pub violations: Vec<BaselineEntry>,
+}
+
+/// A violation accepted by the baseline and how often it occurred when the baseline was written
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct BaselineEntry {
+    pub rule: String,
+    pub parameters: String,
+    pub accessor: String,
+    pub accessed: String,
+    pub count: usize,
+}
+
+/// Result of comparing the current violations with the baseline
+#[derive(Debug, Clone, Default)]
+pub struct BaselineComparison {
+    /// Indices of the violations that are not part of the baseline
+    pub new_violations: Vec<usize>,
+    /// Baseline entries that do not occur anymore, with the number of missing occurrences as count
+    pub stale_entries: Vec<BaselineEntry>,
+}
+
+impl Baseline {
+    pub const VERSION: u32 = 2;
+
+    pub fn new(fingerprints: &[ViolationFingerprint]) -> Self {
+        let mut violations: Vec<BaselineEntry> = Vec::new();
+        let mut sorted_fingerprints: Vec<&ViolationFingerprint> = fingerprints.iter().collect();
+        sorted_fingerprints.sort();
+        for fingerprint in sorted_fingerprints {
+            match violations.last_mut() {
+                Some(entry) if entry.matches(fingerprint) => entry.count += 1,
+                _ => violations.push(BaselineEntry::from(fingerprint)),
+            }
+        }
+        Baseline {
+            version: Baseline::VERSION,
+            violations,
+        }
+    }
+
+    /// Total number of accepted violations
+    pub fn accepted_violations(&self) -> usize {
+        self.violations.iter().map(|entry| entry.count).sum()
+    }
+}
+
+impl BaselineEntry {
+    /// Whether the entry describes the violation, regardless of the count
+    pub fn matches(&self, fingerprint: &ViolationFingerprint) -> bool {
+        self.rule == *fingerprint.rule()
+            && self.parameters == *fingerprint.parameters()
+            && self.accessor == *fingerprint.accessor()
+            && self.accessed == *fingerprint.accessed()
+    }
+}
+
+impl From<&ViolationFingerprint> for BaselineEntry {
+    fn from(fingerprint: &ViolationFingerprint) -> Self {
+        BaselineEntry {
+            rule: fingerprint.rule().clone(),
+            parameters: fingerprint.parameters().clone(),
+            accessor: fingerprint.accessor().clone(),
+            accessed: fingerprint.accessed().clone(),
+            count: 1,
+        }
+    }
+}
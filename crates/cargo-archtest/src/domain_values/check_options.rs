@@ -0,0 +1,86 @@
+use serde_json::Value;
+
+use crate::domain_values::{ColorChoice, OutputFormat};
+
+#[derive(Debug, Clone, Default, StructOpt)]
+pub struct CheckOptions {
+    #[structopt(
+        short,
+        long,
+        about = "Compares layers found with provided",
+        help = "Compares layers found with provided"
+    )]
+    pub check_for_complete_layer_specification: bool,
+    #[structopt(
+        long,
+        about = "Prints the share of modules and items covered by each layer",
+        help = "Prints the share of modules and items covered by each layer"
+    )]
+    pub coverage: bool,
+    #[structopt(
+        long,
+        about = "Lists the layers of the specification that match no module",
+        help = "Lists the layers of the specification that match no module"
+    )]
+    pub list_unmatched_layers: bool,
+    #[structopt(
+        long,
+        about = "Records all current violations in archtest-baseline.json",
+        help = "Records all current violations in archtest-baseline.json"
+    )]
+    pub write_baseline: bool,
+    #[structopt(
+        long,
+        about = "Ignores suppressions in the code that do not give a reason",
+        help = "Ignores suppressions in the code that do not give a reason"
+    )]
+    pub require_suppression_reason: bool,
+    #[structopt(
+        long,
+        number_of_values = 1,
+        about = "Reports violations of the rule as warnings",
+        help = "Reports violations of the rule as warnings"
+    )]
+    pub warn: Vec<String>,
+    #[structopt(
+        long,
+        number_of_values = 1,
+        about = "Fails on violations of the rule",
+        help = "Fails on violations of the rule"
+    )]
+    pub deny: Vec<String>,
+    #[structopt(
+        long,
+        number_of_values = 1,
+        about = "Does not check the rule",
+        help = "Does not check the rule"
+    )]
+    pub allow: Vec<String>,
+    #[structopt(long, about = "Fails on warnings", help = "Fails on warnings")]
+    pub deny_warnings: bool,
+    #[structopt(
+        long,
+        default_value = "text",
+        possible_values = &OutputFormat::NAMES,
+        about = "Output format of the findings",
+        help = "Output format of the findings"
+    )]
+    pub format: OutputFormat,
+    #[structopt(
+        long,
+        default_value = "auto",
+        possible_values = &ColorChoice::NAMES,
+        about = "Colours the diagnostics",
+        help = "Colours the diagnostics"
+    )]
+    pub color: ColorChoice,
+}
+
+/// Number of violations failing the check and of warnings found in a project
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CheckResult {
+    pub errors: usize,
+    pub warnings: usize,
+    /// JSON reports of the checked projects, only collected for machine readable formats
+    pub reports: Vec<Value>,
+}
@@ -0,0 +1,32 @@
+use std::str::FromStr;
+
+/// Whether diagnostics are coloured, set with `--color`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ColorChoice {
+    /// Colours if the diagnostics are written to a terminal and `NO_COLOR` is not set
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    pub const NAMES: [&'static str; 3] = ["auto", "always", "never"];
+}
+
+impl FromStr for ColorChoice {
+    type Err = String;
+
+    fn from_str(color: &str) -> Result<Self, Self::Err> {
+        match color {
+            "auto" => Ok(ColorChoice::Auto),
+            "always" => Ok(ColorChoice::Always),
+            "never" => Ok(ColorChoice::Never),
+            _ => Err(format!(
+                "Unknown color choice '{}', expected one of: {}",
+                color,
+                ColorChoice::NAMES.join(", ")
+            )),
+        }
+    }
+}
@@ -1,13 +1,8 @@
+use crate::domain_values::{CheckOptions, DumpCommand, GraphCommand, QueryCommand, ReportCommand};
+
 #[derive(Debug, StructOpt)]
 pub enum Command {
     Archtest {
-        #[structopt(
-            short,
-            long,
-            about = "Compares layers found with provided",
-            help = "Compares layers found with provided"
-        )]
-        check_for_complete_layer_specification: bool,
         #[structopt(
             short,
             long,
@@ -16,5 +11,39 @@ pub enum Command {
             help = "Path to Cargo.toml"
         )]
         toml_path: String,
+        #[structopt(flatten)]
+        options: CheckOptions,
+        #[structopt(subcommand)]
+        subcommand: Option<Subcommand>,
     },
 }
+
+/// What to do instead of checking the architecture
+#[derive(Debug, StructOpt)]
+pub enum Subcommand {
+    #[structopt(
+        about = "Explains for every rule which modules and uses it inspected",
+        help = "Explains for every rule which modules and uses it inspected"
+    )]
+    Explain,
+    #[structopt(
+        about = "Prints the modules and their dependencies as graph",
+        help = "Prints the modules and their dependencies as graph"
+    )]
+    Graph(GraphCommand),
+    #[structopt(
+        about = "Writes the architecture and the findings as self-contained HTML page",
+        help = "Writes the architecture and the findings as self-contained HTML page"
+    )]
+    Report(ReportCommand),
+    #[structopt(
+        about = "Prints the parsed modules, items and uses, e.g. for own tools",
+        help = "Prints the parsed modules, items and uses, e.g. for own tools"
+    )]
+    Dump(DumpCommand),
+    #[structopt(
+        about = "Answers which modules a module depends on or is used by",
+        help = "Answers which modules a module depends on or is used by"
+    )]
+    Query(QueryCommand),
+}
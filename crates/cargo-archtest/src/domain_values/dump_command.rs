@@ -0,0 +1,14 @@
+use crate::domain_values::DumpFormat;
+
+/// Arguments of `cargo archtest dump`
+#[derive(Debug, Clone, Default, StructOpt)]
+pub struct DumpCommand {
+    #[structopt(
+        long,
+        default_value = "json",
+        possible_values = &DumpFormat::NAMES,
+        about = "Format of the model",
+        help = "Format of the model"
+    )]
+    pub format: DumpFormat,
+}
@@ -0,0 +1,28 @@
+use std::str::FromStr;
+
+/// Format of the model printed by `cargo archtest dump`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum DumpFormat {
+    /// The `ModuleTree` as JSON document, versioned by `schema_version`
+    #[default]
+    Json,
+}
+
+impl DumpFormat {
+    pub const NAMES: [&'static str; 1] = ["json"];
+}
+
+impl FromStr for DumpFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "json" => Ok(DumpFormat::Json),
+            _ => Err(format!(
+                "Unknown dump format '{}', expected one of: {}",
+                format,
+                DumpFormat::NAMES.join(", ")
+            )),
+        }
+    }
+}
@@ -1,5 +1,10 @@
+use crate::domain_values::SpecificationProblem;
+
 #[derive(Debug, Clone)]
 pub enum Failure {
-    SpecificationCouldNotBeParsed,
+    SpecificationCouldNotBeParsed(Vec<SpecificationProblem>),
     SpecificationFileCantBeOpened,
+    BaselineCouldNotBeParsed,
+    BaselineFileCantBeOpened,
+    BaselineCouldNotBeWritten,
 }
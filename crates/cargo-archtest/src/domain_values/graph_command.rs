@@ -0,0 +1,44 @@
+use crate::domain_values::GraphFormat;
+
+/// Arguments of `cargo archtest graph`
+#[derive(Debug, Clone, Default, StructOpt)]
+pub struct GraphCommand {
+    #[structopt(
+        long,
+        default_value = "dot",
+        possible_values = &GraphFormat::NAMES,
+        about = "Format of the graph",
+        help = "Format of the graph"
+    )]
+    pub format: GraphFormat,
+    #[structopt(
+        long,
+        about = "Collapses the modules to the layers of the specification",
+        help = "Collapses the modules to the layers of the specification"
+    )]
+    pub layers: bool,
+    #[structopt(
+        long,
+        about = "Shows the items and their dependencies instead of the modules",
+        help = "Shows the items and their dependencies instead of the modules"
+    )]
+    pub items: bool,
+    #[structopt(
+        long,
+        about = "Collapses modules deeper than the depth into their parent",
+        help = "Collapses modules deeper than the depth into their parent"
+    )]
+    pub depth: Option<usize>,
+    #[structopt(
+        long,
+        about = "Only shows the module with this path and its children, e.g. crate::analyzer",
+        help = "Only shows the module with this path and its children, e.g. crate::analyzer"
+    )]
+    pub subtree: Option<String>,
+    #[structopt(
+        long,
+        about = "Only shows the modules of this layer and the modules they use or are used by",
+        help = "Only shows the modules of this layer and the modules they use or are used by"
+    )]
+    pub neighbourhood: Option<String>,
+}
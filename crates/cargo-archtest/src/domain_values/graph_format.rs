@@ -0,0 +1,50 @@
+use std::str::FromStr;
+
+/// Format of the graph printed by `cargo archtest graph`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum GraphFormat {
+    /// Graphviz DOT
+    #[default]
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
+    /// PlantUML component diagram
+    Plantuml,
+    /// GraphML document, e.g. for yEd
+    Graphml,
+    /// GEXF document, e.g. for Gephi
+    Gexf,
+    /// Structurizr DSL workspace for the C4 model, with a component per layer
+    Structurizr,
+}
+
+impl GraphFormat {
+    pub const NAMES: [&'static str; 6] = [
+        "dot",
+        "mermaid",
+        "plantuml",
+        "graphml",
+        "gexf",
+        "structurizr",
+    ];
+}
+
+impl FromStr for GraphFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "dot" => Ok(GraphFormat::Dot),
+            "mermaid" => Ok(GraphFormat::Mermaid),
+            "plantuml" => Ok(GraphFormat::Plantuml),
+            "graphml" => Ok(GraphFormat::Graphml),
+            "gexf" => Ok(GraphFormat::Gexf),
+            "structurizr" => Ok(GraphFormat::Structurizr),
+            _ => Err(format!(
+                "Unknown graph format '{}', expected one of: {}",
+                format,
+                GraphFormat::NAMES.join(", ")
+            )),
+        }
+    }
+}
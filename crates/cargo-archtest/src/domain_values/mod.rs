@@ -1,9 +1,35 @@
-pub use self::access_rule::AccessRule;
-pub use self::command::Command;
+pub use self::access_rule::{AccessRule, ItemCycleScope};
+pub use self::baseline::{Baseline, BaselineComparison, BaselineEntry};
+pub use self::check_options::{CheckOptions, CheckResult};
+pub use self::color_choice::ColorChoice;
+pub use self::command::{Command, Subcommand};
+pub use self::dump_command::DumpCommand;
+pub use self::dump_format::DumpFormat;
 pub use self::failure::Failure;
+pub use self::graph_command::GraphCommand;
+pub use self::graph_format::GraphFormat;
+pub use self::output_format::OutputFormat;
+pub use self::query_command::QueryCommand;
+pub use self::report_command::ReportCommand;
+pub use self::rule_entry::RuleEntry;
+pub use self::severity::Severity;
 pub use self::specification::Specification;
+pub use self::specification_problem::SpecificationProblem;
 
 mod access_rule;
+mod baseline;
+mod check_options;
+mod color_choice;
 mod command;
+mod dump_command;
+mod dump_format;
 mod failure;
+mod graph_command;
+mod graph_format;
+mod output_format;
+mod query_command;
+mod report_command;
+mod rule_entry;
+mod severity;
 mod specification;
+mod specification_problem;
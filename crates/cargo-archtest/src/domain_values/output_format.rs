@@ -0,0 +1,48 @@
+use std::str::FromStr;
+
+/// How `cargo archtest` prints its findings, set with `--format`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum OutputFormat {
+    /// Human readable text
+    #[default]
+    Text,
+    /// The report as JSON document, see `Report::to_json`
+    Json,
+    /// SARIF 2.1.0 log for code scanning tools
+    Sarif,
+    /// JUnit XML with a testcase per access rule
+    Junit,
+    /// GitHub Actions workflow commands annotating the diff
+    Github,
+    /// GitLab Code Quality report
+    Gitlab,
+}
+
+impl OutputFormat {
+    pub const NAMES: [&'static str; 6] = ["text", "json", "sarif", "junit", "github", "gitlab"];
+
+    /// Machine readable formats print the report to stdout and everything else to stderr
+    pub fn is_text(&self) -> bool {
+        *self == OutputFormat::Text
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "sarif" => Ok(OutputFormat::Sarif),
+            "junit" => Ok(OutputFormat::Junit),
+            "github" => Ok(OutputFormat::Github),
+            "gitlab" => Ok(OutputFormat::Gitlab),
+            _ => Err(format!(
+                "Unknown format '{}', expected one of: {}",
+                format,
+                OutputFormat::NAMES.join(", ")
+            )),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+/// Arguments of `cargo archtest query`
+#[derive(Debug, Clone, Default, StructOpt)]
+pub struct QueryCommand {
+    #[structopt(
+        about = "Fully qualified path of the module, e.g. crate::parser::materials",
+        help = "Fully qualified path of the module, e.g. crate::parser::materials"
+    )]
+    pub module: String,
+    #[structopt(
+        long,
+        about = "Lists the modules using the module instead of the modules it uses",
+        help = "Lists the modules using the module instead of the modules it uses"
+    )]
+    pub dependents: bool,
+    #[structopt(
+        long,
+        about = "Follows the dependencies transitively",
+        help = "Follows the dependencies transitively"
+    )]
+    pub transitive: bool,
+    #[structopt(
+        long,
+        about = "Prints the shortest chain of dependencies from the module to this module",
+        help = "Prints the shortest chain of dependencies from the module to this module"
+    )]
+    pub path_to: Option<String>,
+    #[structopt(
+        long,
+        about = "Lists the items defined in the module",
+        help = "Lists the items defined in the module"
+    )]
+    pub items: bool,
+}
@@ -0,0 +1,10 @@
+/// Arguments of `cargo archtest report`
+#[derive(Debug, Clone, Default, StructOpt)]
+pub struct ReportCommand {
+    #[structopt(
+        long,
+        about = "Directory the self-contained HTML report is written to",
+        help = "Directory the self-contained HTML report is written to"
+    )]
+    pub html: String,
+}
@@ -0,0 +1,22 @@
+use crate::domain_values::{AccessRule, Severity};
+
+/// Entry of `access_rules`, either a plain rule or `{ "rule": ..., "severity": "warn" }`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RuleEntry {
+    Rule(AccessRule),
+    WithSeverity {
+        rule: AccessRule,
+        #[serde(default)]
+        severity: Severity,
+    },
+}
+
+impl RuleEntry {
+    pub fn into_parts(self) -> (AccessRule, Severity) {
+        match self {
+            RuleEntry::Rule(rule) => (rule, Severity::default()),
+            RuleEntry::WithSeverity { rule, severity } => (rule, severity),
+        }
+    }
+}
@@ -0,0 +1,8 @@
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    #[default]
+    Deny,
+    Warn,
+    Allow,
+}
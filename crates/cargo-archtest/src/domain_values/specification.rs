@@ -1,7 +1,7 @@
-use crate::domain_values::AccessRule;
+use crate::domain_values::RuleEntry;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Specification {
     pub layer_names: Vec<String>,
-    pub access_rules: Vec<AccessRule>,
+    pub access_rules: Vec<RuleEntry>,
 }
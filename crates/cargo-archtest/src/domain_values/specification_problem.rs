@@ -0,0 +1,109 @@
+use std::fmt::{Display, Formatter};
+
+/// A problem found in `architecture.json`
+///
+/// Rules are located by their path in the specification, e.g. `access_rules[2].AllOf[0]`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpecificationProblem {
+    InvalidJson {
+        message: String,
+        line: usize,
+        column: usize,
+    },
+    InvalidEntry {
+        location: String,
+        message: String,
+        suggestion: Option<String>,
+    },
+    UnknownLayer {
+        location: String,
+        layer: String,
+        suggestion: Option<String>,
+    },
+    ContradictingRules {
+        location: String,
+        other_location: String,
+        accessor: String,
+        accessed: String,
+    },
+    NeverMatches {
+        location: String,
+        layer: String,
+    },
+    ModulePathNotSupported {
+        location: String,
+        path: String,
+    },
+}
+
+impl SpecificationProblem {
+    /// Problems that make the specification unusable, the remaining ones are warnings
+    ///
+    /// Contradicting rules can still be checked, together they forbid the access.
+    pub fn is_error(&self) -> bool {
+        !matches!(
+            self,
+            SpecificationProblem::NeverMatches { .. }
+                | SpecificationProblem::ContradictingRules { .. }
+        )
+    }
+}
+
+impl Display for SpecificationProblem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SpecificationProblem::InvalidJson {
+                message,
+                line,
+                column,
+            } => write!(f, "Invalid JSON at {}:{}: {}.", line, column, message),
+            SpecificationProblem::InvalidEntry {
+                location,
+                message,
+                suggestion,
+            } => {
+                write!(f, "Invalid entry at {}: {}.", location, message)?;
+                write_suggestion(f, suggestion)
+            }
+            SpecificationProblem::UnknownLayer {
+                location,
+                layer,
+                suggestion,
+            } => {
+                write!(
+                    f,
+                    "Rule at {} uses the layer '{}' which is not part of 'layer_names'.",
+                    location, layer
+                )?;
+                write_suggestion(f, suggestion)
+            }
+            SpecificationProblem::ContradictingRules {
+                location,
+                other_location,
+                accessor,
+                accessed,
+            } => write!(
+                f,
+                "Rules at {} and {} contradict each other: '{}' is allowed and forbidden to access '{}', so the access is forbidden.",
+                location, other_location, accessor, accessed
+            ),
+            SpecificationProblem::NeverMatches { location, layer } => write!(
+                f,
+                "Rule at {} can never match, no module belongs to the layer '{}'.",
+                location, layer
+            ),
+            SpecificationProblem::ModulePathNotSupported { location, path } => write!(
+                f,
+                "Rule at {} uses the module path '{}', only the scope of 'Within' accepts module paths.",
+                location, path
+            ),
+        }
+    }
+}
+
+fn write_suggestion(f: &mut Formatter<'_>, suggestion: &Option<String>) -> std::fmt::Result {
+    match suggestion {
+        Some(suggestion) => write!(f, " Did you mean '{}'?", suggestion),
+        None => Ok(()),
+    }
+}
@@ -46,6 +46,72 @@
 //! ```
 //! Then execute `cargo archtest` in your project directory.
 //!
+//! All problems of the specification are reported at once, e.g. JSON syntax errors with their line and column,
+//! layers missing in `layer_names`, contradicting rules and rules that can never match any module.
+//!
+//! ## Layer coverage
+//! `--check-for-complete-layer-specification` fails for every module that belongs to no layer and lists them with their path and file.
+//! `--coverage` prints the share of modules and items per layer and `--list-unmatched-layers` lists the layers that match no module.
+//!
+//! ## Baseline
+//! To introduce rules into a code base that already violates them, record the current violations with `cargo archtest --write-baseline`.
+//! This writes `archtest-baseline.json` next to the `architecture.json`. Afterwards only violations that are not part of the baseline fail the check,
+//! as well as occurrences beyond the number recorded for a violation.
+//! Baseline entries that do not occur anymore are reported as stale, so the baseline can be shrunk by writing it again.
+//!
+//! ## Diagnostics
+//! Violations are printed like rustc errors: an error code per rule, the file, line and column, the offending use marked
+//! with carets, the accessed item marked as second span and notes naming the violated rule.
+//! Colours are used on a terminal unless `NO_COLOR` is set, `--color always` or `--color never` overrides this.
+//!
+//! ## Severities
+//! An entry in `access_rules` can be written as `{ "rule": ..., "severity": "warn" }` with the severities `deny` (default), `warn` and `allow`.
+//! Warnings are printed but do not change the exit code. On the command line `--deny`, `--warn` and `--allow` override the severity
+//! of a rule by its name, e.g. `--warn NoParentAccess`, and `--deny-warnings` fails on warnings.
+//!
+//! ## Suppressions
+//! Single violations can be accepted in the code with a comment `// archtest:allow(MayNotAccess, reason = "...")`
+//! at the end of the offending line, or in front of an item or a `mod` declaration to accept it for the whole item or module.
+//! The attribute form `#[cfg_attr(archtest, allow_arch(MayNotAccess, reason = "..."))]` works the same way.
+//! Used and unused suppressions are listed, `--require-suppression-reason` ignores suppressions without a reason.
+//!
+//! ## Machine readable output
+//! `--format json` prints one JSON document with the rules and violations of all workspace members to stdout,
+//! following the versioned schema of `Report::to_json` in arch_test_core. Violations accepted by the baseline are listed
+//! under `baselined_violations`. All other messages are written to stderr.
+//! `--format sarif` writes a SARIF 2.1.0 log instead, with a rule descriptor per access rule and the accessed item
+//! and every hop of a cycle as related locations of a result.
+//! `--format junit` writes JUnit XML with a testcase per access rule, failing with the location and snippet of each violation.
+//!
+//! ## Explain
+//! `cargo archtest explain` prints for every rule the accessor modules it selected, the accessed modules it considered,
+//! how many uses were evaluated and which exemptions like `when_same_parent` applied.
+//! A rule that passes because it matches nothing shows up with `none` modules.
+//!
+//! ## Graph
+//! `cargo archtest graph --format dot` prints the modules as clusters and their dependencies weighted by the number of uses
+//! as Graphviz graph, e.g. for `cargo archtest graph | dot -Tsvg > architecture.svg`. Uses that break a rule are drawn red.
+//! `--layers` collapses the modules to the layers of the specification, `--depth 2` collapses deeper modules into their parent
+//! and `--subtree crate::analyzer` restricts the graph to one module and its children.
+//! `--format mermaid` and `--format plantuml` group the modules by layer instead, `--neighbourhood services` only keeps
+//! the modules of one layer and the modules they use or are used by.
+//! `--format graphml` and `--format gexf` export the graph with its attributes for yEd and Gephi,
+//! `--items` shows the items and their dependencies instead of the modules.
+//! `--format structurizr` writes a Structurizr DSL workspace with a component per layer for the C4 model.
+//!
+//! ## HTML report
+//! `cargo archtest report --html out/` writes `out/index.html` with the module tree, a zoomable dependency graph,
+//! the rules with their status and the violations with their annotated source. The page embeds its data and loads nothing else.
+//!
+//! ## Model dump
+//! `cargo archtest dump --format json` prints the parsed modules, items and uses as JSON document versioned by `schema_version`, no `architecture.json` is needed.
+//! `arch_test_core` with the feature `serde` loads it as `ModuleTree` again.
+//!
+//! ## Queries
+//! `cargo archtest query crate::parser::materials` prints the modules the module uses, `--dependents` the modules using it
+//! and `--transitive` follows the uses further. `--path-to crate::utils` prints the shortest chain of uses between the two modules
+//! and `--items` the items defined in the module. Tests ask the same of `ModuleTree::dependencies_of` and its siblings.
+//!
 //! ## Continuous integration
 //! You can use it in continuous integration by using either methods. If you decide to use the Cargo sub command on GitHub, the following snippet will allow you to test your project.
 //! ```yml
@@ -58,7 +124,17 @@
 //!        with:
 //!          crate: cargo-archtest
 //!          version: latest
-//!      - run: cargo archtest
+//!      - run: cargo archtest --format github
+//! ```
+//! `--format github` prints workflow commands, so GitHub annotates the violations in the diff.
+//! On GitLab `--format gitlab` writes a Code Quality report with stable fingerprints:
+//! ```yml
+//! archtest:
+//!   script:
+//!     - cargo archtest --format gitlab > gl-code-quality-report.json
+//!   artifacts:
+//!     reports:
+//!       codequality: gl-code-quality-report.json
 //! ```
 
 extern crate cargo_toml;
@@ -73,8 +149,11 @@ use std::path::Path;
 
 use structopt::StructOpt;
 
-use crate::domain_values::Command;
-use crate::services::check_architecture;
+use crate::domain_values::{CheckResult, Command, Subcommand};
+use crate::services::{
+    check_architecture, dump_model, explain_architecture, export_graph, export_report,
+    print_message, query_modules, render_reports,
+};
 
 mod domain_values;
 mod services;
@@ -84,32 +163,101 @@ mod tests;
 
 fn main() {
     let Command::Archtest {
-        check_for_complete_layer_specification,
         toml_path,
+        options,
+        subcommand,
     } = Command::from_args();
-    let toml_path = Path::new(&toml_path);
-    if toml_path.exists() && toml_path.is_file() {
-        if let Ok(toml) = cargo_toml::Manifest::from_path(toml_path) {
-            if let Some(workspace) = toml.workspace {
-                for member in workspace.members {
-                    if member.contains('*') {
-                        println!("Can not interpret paths with '*'");
-                        std::process::exit(1);
-                    } else {
-                        check_architecture(&member, check_for_complete_layer_specification);
+    let mut result = CheckResult::default();
+    for directory_path in project_directories(Path::new(&toml_path)) {
+        let project_result = match subcommand.as_ref() {
+            Some(Subcommand::Explain) => explain_architecture(&directory_path, &options),
+            Some(Subcommand::Graph(graph_command)) => {
+                match export_graph(&directory_path, &options, graph_command) {
+                    Ok(graph) => {
+                        print!("{}", graph);
+                        CheckResult::default()
+                    }
+                    Err(result) => result,
+                }
+            }
+            Some(Subcommand::Dump(dump_command)) => {
+                match dump_model(&directory_path, &options, dump_command) {
+                    Ok(model) => {
+                        println!("{}", model);
+                        CheckResult::default()
+                    }
+                    Err(result) => result,
+                }
+            }
+            Some(Subcommand::Query(query_command)) => {
+                match query_modules(&directory_path, &options, query_command) {
+                    Ok(answer) => {
+                        print!("{}", answer);
+                        CheckResult::default()
                     }
+                    Err(result) => result,
                 }
-            } else {
-                check_architecture(".", check_for_complete_layer_specification);
             }
+            Some(Subcommand::Report(report_command)) => {
+                export_report(&directory_path, &options, report_command)
+            }
+            None => check_architecture(&directory_path, &options),
+        };
+        result.errors += project_result.errors;
+        result.warnings += project_result.warnings;
+        result.reports.extend(project_result.reports);
+    }
+    if subcommand.is_none() {
+        if let Some(output) = render_reports(options.format, &result.reports) {
+            println!("{}", output);
+        }
+    }
+
+    if result.errors > 0 {
+        print_message(
+            &options,
+            &format!(
+                "[Error]: Found {} errors and {} warnings.",
+                result.errors, result.warnings
+            ),
+        );
+        std::process::exit(1);
+    }
+    if subcommand.is_none() && !options.write_baseline {
+        if result.warnings > 0 {
+            print_message(
+                &options,
+                &format!(
+                    "[Ok]: No architecture rules were violated, {} warnings.",
+                    result.warnings
+                ),
+            );
         } else {
-            println!("Cargo.toml could not be parsed!");
-            std::process::exit(1);
+            print_message(&options, "[Ok]: No architecture rules were violated!");
         }
-    } else {
+    }
+}
+
+/// The workspace members or the project itself, exits if the Cargo.toml can not be interpreted
+fn project_directories(toml_path: &Path) -> Vec<String> {
+    if !toml_path.exists() || !toml_path.is_file() {
         println!("Cargo.toml not found in the specified path!");
         std::process::exit(1);
     }
-
-    println!("[Ok]: No architecture rules were violated!");
+    match cargo_toml::Manifest::from_path(toml_path) {
+        Ok(toml) => match toml.workspace {
+            Some(workspace) => {
+                if workspace.members.iter().any(|member| member.contains('*')) {
+                    println!("Can not interpret paths with '*'");
+                    std::process::exit(1);
+                }
+                workspace.members
+            }
+            None => vec![".".to_owned()],
+        },
+        Err(_) => {
+            println!("Cargo.toml could not be parsed!");
+            std::process::exit(1);
+        }
+    }
 }
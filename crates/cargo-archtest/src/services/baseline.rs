@@ -0,0 +1,64 @@
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::Path;
+
+use arch_test_core::ViolationFingerprint;
+
+use crate::domain_values::{Baseline, BaselineComparison, BaselineEntry, Failure};
+
+pub fn read_baseline(baseline_path: &Path) -> Result<Baseline, Failure> {
+    let mut file = File::open(baseline_path).map_err(|_| Failure::BaselineFileCantBeOpened)?;
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .map_err(|_| Failure::BaselineFileCantBeOpened)?;
+    let baseline: Baseline =
+        serde_json::from_str(&content).map_err(|_| Failure::BaselineCouldNotBeParsed)?;
+    if baseline.version != Baseline::VERSION {
+        return Err(Failure::BaselineCouldNotBeParsed);
+    }
+    Ok(baseline)
+}
+
+pub fn write_baseline(baseline_path: &Path, baseline: &Baseline) -> Result<(), Failure> {
+    let content =
+        serde_json::to_string_pretty(baseline).map_err(|_| Failure::BaselineCouldNotBeWritten)?;
+    let mut file = File::create(baseline_path).map_err(|_| Failure::BaselineCouldNotBeWritten)?;
+    file.write_all(content.as_bytes())
+        .map_err(|_| Failure::BaselineCouldNotBeWritten)
+}
+
+/// Accepts as many occurrences of every violation as the baseline recorded, the violations beyond are new
+pub fn compare_with_baseline(
+    fingerprints: &[ViolationFingerprint],
+    baseline: &Baseline,
+) -> BaselineComparison {
+    let mut remaining: Vec<usize> = baseline
+        .violations
+        .iter()
+        .map(|entry| entry.count)
+        .collect();
+    let mut new_violations = Vec::new();
+    for (index, fingerprint) in fingerprints.iter().enumerate() {
+        match baseline
+            .violations
+            .iter()
+            .position(|entry| entry.matches(fingerprint))
+        {
+            Some(entry_index) if remaining[entry_index] > 0 => remaining[entry_index] -= 1,
+            _ => new_violations.push(index),
+        }
+    }
+    BaselineComparison {
+        new_violations,
+        stale_entries: baseline
+            .violations
+            .iter()
+            .zip(remaining)
+            .filter(|(_, missing)| *missing > 0)
+            .map(|(entry, missing)| BaselineEntry {
+                count: missing,
+                ..entry.clone()
+            })
+            .collect(),
+    }
+}
@@ -1,38 +1,187 @@
 use std::path::Path;
 
-use arch_test_core::ModuleTree;
+use arch_test_core::{ModuleTree, Report, RuleViolation, Severity, ViolationFingerprint};
+use serde_json::Value;
 
-use crate::services::parse_specification;
+use crate::domain_values::{Baseline, CheckOptions, CheckResult};
+use crate::services::{
+    compare_with_baseline, diagnostic_style, load_architecture, print_message, read_baseline,
+    write_baseline,
+};
 
-pub fn check_architecture(directory_path: &str, check_for_complete_layer_specification: bool) {
-    let main_path_str = format!("{}/src/main.rs", directory_path);
-    let main_path = Path::new(&main_path_str);
-    let root_path = if main_path.exists() && main_path.is_file() {
-        format!("{}/src/main.rs", directory_path)
-    } else {
-        format!("{}/src/lib.rs", directory_path)
+/// Checks the project in the directory and prints every error and warning found
+pub fn check_architecture(directory_path: &str, options: &CheckOptions) -> CheckResult {
+    let mut result = CheckResult::default();
+    let (architecture, module_tree) = match load_architecture(directory_path, options, &mut result)
+    {
+        Some(loaded) => loaded,
+        None => return result,
     };
-    let specification_path = format!("{}/architecture.json", directory_path);
-    let specification = parse_specification(Path::new(&specification_path));
-
-    if let Ok(architecture) = specification {
-        let module_tree = ModuleTree::new(&root_path);
-        if let Err(err) = architecture.validate_access_rules() {
-            err.print(&module_tree.tree());
-            std::process::exit(1);
-        } else if let Err(err) = architecture.check_access_rules(&module_tree) {
-            err.print(&module_tree.tree());
-            std::process::exit(1);
-        } else if check_for_complete_layer_specification {
-            if let Err(err) = architecture.check_complete_layer_specification(&module_tree) {
-                err.print(&module_tree.tree());
-                std::process::exit(1);
+
+    if options.coverage || options.list_unmatched_layers {
+        let coverage = architecture.layer_coverage(&module_tree);
+        if options.coverage {
+            print_message(options, &coverage.to_string());
+        }
+        if options.list_unmatched_layers {
+            for layer in coverage.unmatched_layers() {
+                print_message(
+                    options,
+                    &format!("[Unmatched]: Layer '{}' does not match any module.", layer),
+                );
+            }
+        }
+    }
+
+    let report = architecture.report(&module_tree);
+    for suppression in report.used_suppressions() {
+        print_message(options, &format!("[Suppressed]: {}", suppression));
+    }
+    for suppression in report.unused_suppressions() {
+        print_message(
+            options,
+            &format!(
+                "[Unused]: Suppression {} does not suppress any violation.",
+                suppression
+            ),
+        );
+    }
+    for suppression in report.suppressions_without_reason() {
+        print_message(
+            options,
+            &format!(
+                "[Ignored]: Suppression {} does not give a reason.",
+                suppression
+            ),
+        );
+    }
+    for warning in report.warnings() {
+        print_message(
+            options,
+            &warning.render_with(&module_tree, diagnostic_style(options, Severity::Warn)),
+        );
+    }
+    result.warnings += report.warnings().len();
+
+    let violations = report.violations();
+    let fingerprints: Vec<ViolationFingerprint> = violations
+        .iter()
+        .map(|violation| violation.fingerprint())
+        .collect();
+    let baseline_path_str = format!("{}/archtest-baseline.json", directory_path);
+    let baseline_path = Path::new(&baseline_path_str);
+    if options.write_baseline {
+        let baseline = Baseline::new(&fingerprints);
+        if write_baseline(baseline_path, &baseline).is_err() {
+            print_message(
+                options,
+                &format!("Baseline file cant be written for '{}'.", directory_path),
+            );
+            result.errors += 1;
+            return result;
+        }
+        print_message(
+            options,
+            &format!(
+                "[Ok]: Recorded {} violations in '{}'.",
+                baseline.accepted_violations(),
+                baseline_path_str
+            ),
+        );
+        return result;
+    }
+
+    let new_violations: Vec<usize> = if baseline_path.exists() {
+        if let Ok(baseline) = read_baseline(baseline_path) {
+            let comparison = compare_with_baseline(&fingerprints, &baseline);
+            for entry in comparison.stale_entries.iter() {
+                print_message(
+                    options,
+                    &if entry.count == 1 {
+                        format!(
+                            "[Stale]: Baseline entry '{}' from '{}' to '{}' does not occur anymore.",
+                            entry.rule, entry.accessor, entry.accessed
+                        )
+                    } else {
+                        format!(
+                            "[Stale]: Baseline entry '{}' from '{}' to '{}' occurs {} times less than recorded.",
+                            entry.rule, entry.accessor, entry.accessed, entry.count
+                        )
+                    },
+                );
             }
+            comparison.new_violations
+        } else {
+            print_message(
+                options,
+                &format!("Baseline file cant be parsed for '{}'.", directory_path),
+            );
+            result.errors += 1;
+            return result;
         }
     } else {
-        println!(
-            "Specification file cant be opened for '{}'.",
-            directory_path
+        (0..violations.len()).collect()
+    };
+    for index in new_violations.iter() {
+        print_message(
+            options,
+            &violations[*index]
+                .render_with(&module_tree, diagnostic_style(options, Severity::Deny)),
         );
+        result.errors += 1;
+    }
+
+    let mut layer_specification_violation = None;
+    if options.check_for_complete_layer_specification {
+        if let Err(err) = architecture.check_complete_layer_specification(&module_tree) {
+            print_message(
+                options,
+                err.render_with(&module_tree, diagnostic_style(options, Severity::Deny))
+                    .trim_end(),
+            );
+            result.errors += 1;
+            layer_specification_violation = Some(err);
+        }
+    }
+
+    if !options.format.is_text() {
+        result.reports.push(report_json(
+            &report,
+            &module_tree,
+            &new_violations,
+            layer_specification_violation.as_ref(),
+        ));
+    }
+    result
+}
+
+/// The report as JSON, violations accepted by the baseline are moved to `baselined_violations`
+fn report_json(
+    report: &Report,
+    module_tree: &ModuleTree,
+    new_violations: &[usize],
+    layer_specification_violation: Option<&RuleViolation>,
+) -> Value {
+    let mut json = report.to_json_value(module_tree);
+    let mut violations = Vec::new();
+    let mut baselined_violations = Vec::new();
+    for (index, violation) in json["violations"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default()
+        .into_iter()
+        .enumerate()
+    {
+        if new_violations.contains(&index) {
+            violations.push(violation);
+        } else {
+            baselined_violations.push(violation);
+        }
+    }
+    if let Some(violation) = layer_specification_violation {
+        violations.push(violation.to_json_value(module_tree));
     }
+    json["violations"] = Value::Array(violations);
+    json["baselined_violations"] = Value::Array(baselined_violations);
+    json
 }
@@ -0,0 +1,39 @@
+use std::path::Path;
+
+use arch_test_core::ModuleTree;
+
+use crate::domain_values::{CheckOptions, CheckResult, DumpCommand, DumpFormat};
+use crate::services::{crate_root, print_message};
+
+/// The parsed model of the project, i.e. its modules, items and uses, see `ModuleTree`
+///
+/// The project is parsed without its specification, so no `architecture.json` is needed.
+/// Fails if the crate root does not exist or the model can not be serialized.
+pub fn dump_model(
+    directory_path: &str,
+    options: &CheckOptions,
+    dump_command: &DumpCommand,
+) -> Result<String, CheckResult> {
+    let mut result = CheckResult::default();
+    let root_path = crate_root(directory_path);
+    if !Path::new(&root_path).is_file() {
+        print_message(
+            options,
+            &format!("[Error]: No crate root found at '{}'.", root_path),
+        );
+        result.errors += 1;
+        return Err(result);
+    }
+    let module_tree = ModuleTree::new(&root_path);
+    let model = match dump_command.format {
+        DumpFormat::Json => serde_json::to_string_pretty(&module_tree),
+    };
+    model.map_err(|err| {
+        print_message(
+            options,
+            &format!("[Error]: Model could not be serialized: {}", err),
+        );
+        result.errors += 1;
+        result
+    })
+}
@@ -0,0 +1,16 @@
+use crate::domain_values::{CheckOptions, CheckResult};
+use crate::services::{load_architecture, print_message};
+
+/// Prints for every rule of the project which modules and uses it inspected
+pub fn explain_architecture(directory_path: &str, options: &CheckOptions) -> CheckResult {
+    let mut result = CheckResult::default();
+    let (architecture, module_tree) = match load_architecture(directory_path, options, &mut result)
+    {
+        Some(loaded) => loaded,
+        None => return result,
+    };
+    for explanation in architecture.explain(&module_tree) {
+        print_message(options, &explanation.to_string());
+    }
+    result
+}
@@ -0,0 +1,80 @@
+use std::path::Path;
+
+use arch_test_core::{Architecture, ModuleTree};
+
+use crate::domain_values::{CheckOptions, CheckResult, GraphCommand, GraphFormat};
+use crate::services::load_architecture;
+
+/// The module graph of the project, uses that break a rule are highlighted
+///
+/// Fails with the errors of loading the architecture.
+pub fn export_graph(
+    directory_path: &str,
+    options: &CheckOptions,
+    graph_command: &GraphCommand,
+) -> Result<String, CheckResult> {
+    let mut result = CheckResult::default();
+    match load_architecture(directory_path, options, &mut result) {
+        Some((architecture, module_tree)) => Ok(render_graph(
+            &architecture,
+            &module_tree,
+            graph_command,
+            &project_name(directory_path),
+        )),
+        None => Err(result),
+    }
+}
+
+fn render_graph(
+    architecture: &Architecture,
+    module_tree: &ModuleTree,
+    graph_command: &GraphCommand,
+    project_name: &str,
+) -> String {
+    let mut graph_options = architecture
+        .graph_options(module_tree)
+        .with_layers(architecture.layer_names().iter().cloned());
+    // C4 components stand for layers unless the items are requested
+    let is_structurizr = graph_command.format == GraphFormat::Structurizr;
+    if graph_command.layers || (is_structurizr && !graph_command.items) {
+        graph_options = graph_options.with_collapsed_layers();
+    }
+    if graph_command.items {
+        graph_options = graph_options.with_items();
+    }
+    if let Some(layer) = graph_command.neighbourhood.as_ref() {
+        graph_options = graph_options.with_neighbourhood_of(layer.clone());
+    }
+    if let Some(depth) = graph_command.depth {
+        graph_options = graph_options.with_max_depth(depth);
+    }
+    if let Some(subtree) = graph_command.subtree.as_ref() {
+        graph_options = graph_options.with_subtree(subtree.clone());
+    }
+    let module_graph = module_tree.module_graph(&graph_options);
+    match graph_command.format {
+        GraphFormat::Dot => module_graph.to_dot(),
+        GraphFormat::Mermaid => module_graph.to_mermaid(),
+        GraphFormat::Plantuml => module_graph.to_plantuml(),
+        GraphFormat::Graphml => module_graph.to_graphml(),
+        GraphFormat::Gexf => module_graph.to_gexf(),
+        GraphFormat::Structurizr => module_graph.to_structurizr(project_name),
+    }
+}
+
+/// Package name of the project, the directory name if the Cargo.toml has no package
+fn project_name(directory_path: &str) -> String {
+    let toml_path = format!("{}/Cargo.toml", directory_path);
+    cargo_toml::Manifest::from_path(&toml_path)
+        .ok()
+        .and_then(|manifest| manifest.package)
+        .map(|package| package.name)
+        .or_else(|| {
+            Path::new(directory_path)
+                .canonicalize()
+                .ok()?
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+        })
+        .unwrap_or_else(|| "architecture".to_owned())
+}
@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use arch_test_core::{Architecture, DiagnosticStyle, ModuleTree, RuleViolation, Severity};
+use serde_json::{json, Value};
+
+use crate::domain_values::{CheckOptions, CheckResult, ReportCommand};
+use crate::services::{html_report, load_architecture, print_message};
+
+/// Writes the HTML report of the project into the directory of the command
+///
+/// The report of a workspace member is written into a subdirectory named like the member.
+pub fn export_report(
+    directory_path: &str,
+    options: &CheckOptions,
+    report_command: &ReportCommand,
+) -> CheckResult {
+    let mut result = CheckResult::default();
+    let (architecture, module_tree) = match load_architecture(directory_path, options, &mut result)
+    {
+        Some(loaded) => loaded,
+        None => return result,
+    };
+    let report_path = report_path(&report_command.html, directory_path);
+    let html = html_report(&report_data(directory_path, &architecture, &module_tree));
+    let written = report_path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&report_path, html));
+    if written.is_err() {
+        print_message(
+            options,
+            &format!(
+                "Report cant be written to '{}'.",
+                report_path.to_string_lossy()
+            ),
+        );
+        result.errors += 1;
+    } else {
+        print_message(
+            options,
+            &format!(
+                "[Ok]: Wrote the report to '{}'.",
+                report_path.to_string_lossy()
+            ),
+        );
+    }
+    result
+}
+
+fn report_path(output_directory: &str, directory_path: &str) -> PathBuf {
+    let mut report_path = Path::new(output_directory).to_path_buf();
+    let member = directory_path.trim_start_matches("./");
+    if member != "." && !member.is_empty() {
+        report_path.push(member);
+    }
+    report_path.push("index.html");
+    report_path
+}
+
+/// Data embedded into the HTML report, see `html_report`
+fn report_data(
+    directory_path: &str,
+    architecture: &Architecture,
+    module_tree: &ModuleTree,
+) -> Value {
+    let report = architecture.report(module_tree);
+    let mut json = report.to_json_value(module_tree);
+    add_diagnostics(
+        &mut json["violations"],
+        report.violations(),
+        module_tree,
+        Severity::Deny,
+    );
+    add_diagnostics(
+        &mut json["warnings"],
+        report.warnings(),
+        module_tree,
+        Severity::Warn,
+    );
+    add_diagnostics(
+        &mut json["suppressed_violations"],
+        report
+            .suppressed_violations()
+            .iter()
+            .map(|(violation, _)| violation),
+        module_tree,
+        Severity::Allow,
+    );
+    let statuses: Vec<&str> = report
+        .rules()
+        .iter()
+        .enumerate()
+        .map(|(index, (_, severity))| rule_status(&json, index, *severity))
+        .collect();
+    for (rule, status) in json["rules"]
+        .as_array_mut()
+        .into_iter()
+        .flatten()
+        .zip(statuses)
+    {
+        rule["status"] = json!(status);
+    }
+
+    let files: HashMap<String, &String> = module_tree
+        .tree()
+        .iter()
+        .map(|node| {
+            (
+                node.get_fully_qualified_path(module_tree.tree()),
+                node.file_path(),
+            )
+        })
+        .collect();
+    let graph_options = architecture
+        .graph_options(module_tree)
+        .with_layers(architecture.layer_names().iter().cloned());
+    let module_graph = module_tree.module_graph(&graph_options);
+    json!({
+        "project": directory_path,
+        "report": json,
+        "graph": {
+            "nodes": module_graph.nodes().iter().map(|node| json!({
+                "id": node.id(),
+                "name": node.name(),
+                "path": node.path(),
+                "parent": node.parent(),
+                "layer": node.layer(),
+                "file": files.get(node.path()),
+            })).collect::<Vec<Value>>(),
+            "edges": module_graph.edges().iter().map(|edge| json!({
+                "from": edge.from(),
+                "to": edge.to(),
+                "weight": edge.weight(),
+                "highlighted": edge.highlighted(),
+            })).collect::<Vec<Value>>(),
+        },
+    })
+}
+
+/// Adds the rendered diagnostic without colour to the JSON of every violation
+fn add_diagnostics<'a>(
+    json: &mut Value,
+    violations: impl IntoIterator<Item = &'a RuleViolation>,
+    module_tree: &ModuleTree,
+    severity: Severity,
+) {
+    for (violation_json, violation) in json.as_array_mut().into_iter().flatten().zip(violations) {
+        violation_json["diagnostic"] =
+            json!(violation.render_with(module_tree, DiagnosticStyle::new(severity, false)));
+    }
+}
+
+/// `failed`, `warned`, `allowed` or `passed` depending on the severity and the violations of the rule
+fn rule_status(json: &Value, rule_index: usize, severity: Severity) -> &'static str {
+    let is_violated = |key: &str| {
+        json[key]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .any(|violation| violation["rule_index"].as_u64() == Some(rule_index as u64))
+    };
+    if severity == Severity::Allow {
+        "allowed"
+    } else if is_violated("violations") {
+        "failed"
+    } else if is_violated("warnings") {
+        "warned"
+    } else {
+        "passed"
+    }
+}
@@ -0,0 +1,57 @@
+use serde_json::Value;
+
+use crate::services::report_values::{array, path_of, rule_name_of};
+
+/// Converts a report of the JSON schema of `Report::to_json` into GitHub Actions workflow commands
+///
+/// Violations become `::error` and warnings `::warning` annotations at the accessor,
+/// violations without a use, e.g. modules without layer, are annotated at the file of each module.
+pub fn github_annotations(report: &Value) -> String {
+    let mut commands = Vec::new();
+    for (key, command) in [("violations", "error"), ("warnings", "warning")] {
+        for violation in array(&report[key]) {
+            let title = rule_name_of(violation);
+            let message = violation["message"].as_str().unwrap_or_default();
+            let accessor = &violation["accessor"];
+            if !accessor.is_null() {
+                commands.push(format!(
+                    "::{} file={},line={},col={},endLine={},endColumn={},title={}::{}",
+                    command,
+                    escape_property(&path_of(&accessor["file"])),
+                    accessor["line"],
+                    accessor["column"],
+                    accessor["end_line"],
+                    accessor["end_column"],
+                    escape_property(title),
+                    escape_data(message)
+                ));
+            }
+            for module in array(&violation["modules"]) {
+                commands.push(format!(
+                    "::{} file={},title={}::{}",
+                    command,
+                    escape_property(&path_of(&module["file"])),
+                    escape_property(title),
+                    escape_data(&format!(
+                        "{}: {}",
+                        message,
+                        module["module_path"].as_str().unwrap_or_default()
+                    ))
+                ));
+            }
+        }
+    }
+    commands.join("\n")
+}
+
+fn escape_data(data: &str) -> String {
+    data.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_property(property: &str) -> String {
+    escape_data(property)
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+}
@@ -0,0 +1,51 @@
+use serde_json::{json, Value};
+
+use crate::services::report_values::{array, path_of, rule_name_of, OccurrenceFingerprints};
+
+/// Converts a report of the JSON schema of `Report::to_json` into a GitLab Code Quality report
+///
+/// Issues are identified like the results of the SARIF report, see `OccurrenceFingerprints`.
+pub fn gitlab_code_quality(report: &Value) -> Value {
+    let mut issues = Vec::new();
+    let mut fingerprints = OccurrenceFingerprints::default();
+    for (key, severity) in [("violations", "major"), ("warnings", "minor")] {
+        for violation in array(&report[key]) {
+            let check_name = rule_name_of(violation);
+            let description = violation["message"].as_str().unwrap_or_default();
+            let accessor = &violation["accessor"];
+            if !accessor.is_null() {
+                issues.push(json!({
+                    "type": "issue",
+                    "check_name": check_name,
+                    "description": description,
+                    "categories": ["Style"],
+                    "severity": severity,
+                    "fingerprint": fingerprints.next(violation, None),
+                    "location": {
+                        "path": path_of(&accessor["file"]),
+                        "positions": {
+                            "begin": { "line": accessor["line"], "column": accessor["column"] },
+                            "end": { "line": accessor["end_line"], "column": accessor["end_column"] },
+                        }
+                    }
+                }));
+            }
+            for module in array(&violation["modules"]) {
+                let module_path = module["module_path"].as_str().unwrap_or_default();
+                issues.push(json!({
+                    "type": "issue",
+                    "check_name": check_name,
+                    "description": format!("{}: {}", description, module_path),
+                    "categories": ["Style"],
+                    "severity": severity,
+                    "fingerprint": fingerprints.next(violation, Some(module_path)),
+                    "location": {
+                        "path": path_of(&module["file"]),
+                        "lines": { "begin": 1 }
+                    }
+                }));
+            }
+        }
+    }
+    Value::Array(issues)
+}
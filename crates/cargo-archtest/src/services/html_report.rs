@@ -0,0 +1,15 @@
+use serde_json::Value;
+
+const TEMPLATE: &str = include_str!("html_report.html");
+const DATA_PLACEHOLDER: &str = "/*ARCHTEST_DATA*/";
+
+/// Self-contained HTML page of the report data, the data is embedded as JSON and rendered without external resources
+///
+/// The data consists of the `project`, the `report` with the `status` of every rule and the `diagnostic` of every violation,
+/// and the module `graph` with `nodes` and `edges`.
+pub fn html_report(data: &Value) -> String {
+    let json = serde_json::to_string(data)
+        .unwrap_or_default()
+        .replace("</", "<\\/");
+    TEMPLATE.replace(DATA_PLACEHOLDER, &json)
+}
@@ -0,0 +1,174 @@
+use serde_json::Value;
+
+use crate::services::report_values::array;
+
+/// Converts a report of the JSON schema of `Report::to_json` into JUnit XML
+///
+/// Every configured access rule is a testcase, failing with one failure per violation.
+/// Rules with severity `allow` are skipped, warnings are attached as output of a passing testcase.
+/// Violations without a rule, e.g. of the complete layer specification, get a testcase named after their rule.
+pub fn junit_report(report: &Value) -> String {
+    let mut testcases: Vec<Testcase> = array(&report["rules"])
+        .iter()
+        .map(|rule| Testcase {
+            name: rule_name(rule),
+            skipped: rule["severity"] == "allow",
+            failures: vec![],
+            warnings: vec![],
+        })
+        .collect();
+    for (key, is_failure) in [("violations", true), ("warnings", false)] {
+        for violation in array(&report[key]) {
+            let index = match violation["rule_index"].as_u64() {
+                Some(index) if (index as usize) < testcases.len() => index as usize,
+                _ => {
+                    let name = rule_name(&violation["rule"]);
+                    match testcases.iter().position(|testcase| testcase.name == name) {
+                        Some(index) => index,
+                        None => {
+                            testcases.push(Testcase {
+                                name,
+                                skipped: false,
+                                failures: vec![],
+                                warnings: vec![],
+                            });
+                            testcases.len() - 1
+                        }
+                    }
+                }
+            };
+            if is_failure {
+                testcases[index].failures.push(violation);
+            } else {
+                testcases[index].warnings.push(violation);
+            }
+        }
+    }
+
+    let failures: usize = testcases
+        .iter()
+        .filter(|testcase| !testcase.failures.is_empty())
+        .count();
+    let skipped = testcases.iter().filter(|testcase| testcase.skipped).count();
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<testsuites name=\"cargo-archtest\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"{}\">\n",
+        testcases.len(),
+        failures,
+        skipped
+    ));
+    xml.push_str(&format!(
+        "  <testsuite name=\"architecture\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"{}\">\n",
+        testcases.len(),
+        failures,
+        skipped
+    ));
+    for testcase in testcases.iter() {
+        xml.push_str(&format!(
+            "    <testcase classname=\"archtest\" name=\"{}\"",
+            escape(&testcase.name)
+        ));
+        if !testcase.skipped && testcase.failures.is_empty() && testcase.warnings.is_empty() {
+            xml.push_str("/>\n");
+            continue;
+        }
+        xml.push_str(">\n");
+        if testcase.skipped {
+            xml.push_str("      <skipped message=\"severity allow\"/>\n");
+        }
+        for violation in testcase.failures.iter() {
+            xml.push_str(&format!(
+                "      <failure message=\"{}\" type=\"{}\">{}</failure>\n",
+                escape(violation["message"].as_str().unwrap_or_default()),
+                escape(violation["violation_type"].as_str().unwrap_or_default()),
+                escape(&violation_details(violation))
+            ));
+        }
+        if !testcase.warnings.is_empty() {
+            let warnings: Vec<String> = testcase
+                .warnings
+                .iter()
+                .map(|violation| {
+                    format!(
+                        "warning: {}\n{}",
+                        violation["message"].as_str().unwrap_or_default(),
+                        violation_details(violation)
+                    )
+                })
+                .collect();
+            xml.push_str(&format!(
+                "      <system-out>{}</system-out>\n",
+                escape(&warnings.join("\n\n"))
+            ));
+        }
+        xml.push_str("    </testcase>\n");
+    }
+    xml.push_str("  </testsuite>\n</testsuites>\n");
+    xml
+}
+
+struct Testcase<'a> {
+    name: String,
+    skipped: bool,
+    failures: Vec<&'a Value>,
+    warnings: Vec<&'a Value>,
+}
+
+/// Name of the rule with its parameters, e.g. `MayNotAccess(accessed = [materials], accessor = services)`
+fn rule_name(rule: &Value) -> String {
+    let name = rule["name"].as_str().unwrap_or_default();
+    let parameters: Vec<String> = rule["parameters"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .map(|(key, value)| {
+            let value = value
+                .as_str()
+                .map_or_else(|| value.to_string(), str::to_owned);
+            format!("{} = {}", key, value)
+        })
+        .collect();
+    if parameters.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{}({})", name, parameters.join(", "))
+    }
+}
+
+/// Source locations and snippets of the accessor and the accessed item, or the modules of the violation
+fn violation_details(violation: &Value) -> String {
+    let mut lines = Vec::new();
+    for key in ["accessor", "accessed"] {
+        let location = &violation[key];
+        if location.is_null() {
+            continue;
+        }
+        lines.push(format!(
+            "{} {} at {}:{}:{}",
+            key,
+            location["item_path"].as_str().unwrap_or_default(),
+            location["file"].as_str().unwrap_or_default(),
+            location["line"],
+            location["column"]
+        ));
+        if let Some(snippet) = location["snippet"].as_str() {
+            lines.push(format!("    {}", snippet.trim()));
+        }
+    }
+    for module in array(&violation["modules"]) {
+        lines.push(format!(
+            "module {} in {}",
+            module["module_path"].as_str().unwrap_or_default(),
+            module["file"].as_str().unwrap_or_default()
+        ));
+    }
+    lines.join("\n")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
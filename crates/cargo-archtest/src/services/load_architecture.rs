@@ -0,0 +1,96 @@
+use std::path::Path;
+
+use arch_test_core::{Architecture, ModuleTree, Severity};
+
+use crate::domain_values::{CheckOptions, CheckResult, Failure};
+use crate::services::{
+    build_architecture, diagnostic_style, print_message, read_specification, validate_specification,
+};
+
+/// Path of the crate root of the project in the directory, `src/main.rs` if it exists, else `src/lib.rs`
+pub fn crate_root(directory_path: &str) -> String {
+    let main_path_str = format!("{}/src/main.rs", directory_path);
+    let main_path = Path::new(&main_path_str);
+    if main_path.exists() && main_path.is_file() {
+        main_path_str
+    } else {
+        format!("{}/src/lib.rs", directory_path)
+    }
+}
+
+/// Parses the project in the directory and builds the architecture of its specification
+///
+/// Problems of the specification are printed and counted in `result`.
+/// Returns `None` if the specification can not be checked because of errors.
+pub fn load_architecture(
+    directory_path: &str,
+    options: &CheckOptions,
+    result: &mut CheckResult,
+) -> Option<(Architecture<'static>, ModuleTree)> {
+    let root_path = crate_root(directory_path);
+    let specification_path = format!("{}/architecture.json", directory_path);
+    let specification = match read_specification(Path::new(&specification_path)) {
+        Ok(specification) => specification,
+        Err(Failure::SpecificationCouldNotBeParsed(problems)) => {
+            for problem in problems.iter() {
+                print_message(options, &format!("[Error]: {}", problem));
+            }
+            result.errors += problems.len();
+            return None;
+        }
+        Err(_) => {
+            print_message(
+                options,
+                &format!(
+                    "Specification file cant be opened for '{}'.",
+                    directory_path
+                ),
+            );
+            return None;
+        }
+    };
+
+    let module_tree = ModuleTree::new(&root_path);
+    let mut has_errors = false;
+    for problem in validate_specification(&specification, &module_tree) {
+        if problem.is_error() {
+            print_message(options, &format!("[Error]: {}", problem));
+            result.errors += 1;
+            has_errors = true;
+        } else {
+            print_message(options, &format!("[Warning]: {}", problem));
+            result.warnings += 1;
+        }
+    }
+    if has_errors {
+        return None;
+    }
+
+    let mut architecture = build_architecture(specification);
+    for (rule_names, severity) in [
+        (&options.deny, Severity::Deny),
+        (&options.warn, Severity::Warn),
+        (&options.allow, Severity::Allow),
+    ] {
+        for rule_name in rule_names.iter() {
+            architecture = architecture.with_severity_override(rule_name, severity);
+        }
+    }
+    if options.deny_warnings {
+        architecture = architecture.with_denied_warnings();
+    }
+    if options.require_suppression_reason {
+        architecture = architecture.with_required_suppression_reason();
+    }
+
+    if let Err(err) = architecture.validate_access_rules() {
+        print_message(
+            options,
+            err.render_with(&module_tree, diagnostic_style(options, Severity::Deny))
+                .trim_end(),
+        );
+        result.errors += 1;
+        return None;
+    }
+    Some((architecture, module_tree))
+}
@@ -1,5 +1,36 @@
+pub use self::baseline::{compare_with_baseline, read_baseline, write_baseline};
 pub use self::check_architecture::check_architecture;
-pub use self::parse_specification::parse_specification;
+pub use self::closest_match::closest_match;
+pub use self::dump_model::dump_model;
+pub use self::explain_architecture::explain_architecture;
+pub use self::export_graph::export_graph;
+pub use self::export_report::export_report;
+pub use self::github_annotations::github_annotations;
+pub use self::gitlab_code_quality::gitlab_code_quality;
+pub use self::html_report::html_report;
+pub use self::junit::junit_report;
+pub use self::load_architecture::{crate_root, load_architecture};
+pub use self::parse_specification::{build_architecture, read_specification};
+pub use self::query_modules::query_modules;
+pub use self::report_output::{diagnostic_style, print_message, render_reports};
+pub use self::sarif::sarif_report;
+pub use self::validate_specification::validate_specification;
 
+mod baseline;
 mod check_architecture;
+mod closest_match;
+mod dump_model;
+mod explain_architecture;
+mod export_graph;
+mod export_report;
+mod github_annotations;
+mod gitlab_code_quality;
+mod html_report;
+mod junit;
+mod load_architecture;
 mod parse_specification;
+mod query_modules;
+mod report_output;
+mod report_values;
+mod sarif;
+mod validate_specification;
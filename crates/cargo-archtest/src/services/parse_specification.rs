@@ -3,78 +3,197 @@ use std::io::Read;
 use std::path::Path;
 
 use arch_test_core::access_rules::{
-    MayNotAccess, MayNotBeAccessedBy, MayOnlyAccess, MayOnlyBeAccessedBy,
-    NoLayerCyclicDependencies, NoModuleCyclicDependencies, NoParentAccess,
+    AllOf, AnyOf, ItemCycleScope as CoreItemCycleScope, MayNotAccess, MayNotBeAccessedBy,
+    MayOnlyAccess, MayOnlyBeAccessedBy, NoItemCyclicDependencies, NoLayerCyclicDependencies,
+    NoModuleCyclicDependencies, NoParentAccess, Not, Within,
 };
 use arch_test_core::hash_set;
-use arch_test_core::Architecture;
+use arch_test_core::{AccessRule as CoreAccessRule, Architecture, Severity as CoreSeverity};
+use serde_json::Value;
 
-use crate::domain_values::{AccessRule, Failure, Specification};
+use crate::domain_values::{
+    AccessRule, Failure, ItemCycleScope, RuleEntry, Severity, Specification, SpecificationProblem,
+};
+use crate::services::closest_match;
 
-pub fn parse_specification(specification_path: &Path) -> Result<Architecture, Failure> {
-    let specification: Specification =
-        serde_json::from_str(&read_file_content(specification_path)?)
-            .map_err(|_| Failure::SpecificationCouldNotBeParsed)?;
+/// Names of the rules that can be used in `access_rules`
+const RULE_NAMES: [&str; 12] = [
+    "NoParentAccess",
+    "NoModuleCyclicDependencies",
+    "NoLayerCyclicDependencies",
+    "NoItemCyclicDependencies",
+    "MayOnlyAccess",
+    "MayNotAccess",
+    "MayOnlyBeAccessedBy",
+    "MayNotBeAccessedBy",
+    "AllOf",
+    "AnyOf",
+    "Not",
+    "Within",
+];
 
-    let mut architecture = Architecture::new(hash_set![..specification.clone().layer_names]);
-    for access_rule in specification.access_rules {
-        match access_rule {
-            AccessRule::NoLayerCyclicDependencies => {
-                architecture = architecture.with_access_rule(NoLayerCyclicDependencies)
-            }
-            AccessRule::NoModuleCyclicDependencies => {
-                architecture = architecture.with_access_rule(NoModuleCyclicDependencies)
-            }
-            AccessRule::NoParentAccess => {
-                architecture = architecture.with_access_rule(NoParentAccess)
-            }
-            AccessRule::MayOnlyAccess {
-                accessor,
-                accessed,
-                when_same_parent,
-            } => {
-                architecture = architecture.with_access_rule(MayOnlyAccess::new(
-                    accessor,
-                    hash_set![..accessed],
-                    when_same_parent,
-                ))
-            }
-            AccessRule::MayNotAccess {
-                accessor,
-                accessed,
-                when_same_parent,
-            } => {
-                architecture = architecture.with_access_rule(MayNotAccess::new(
-                    accessor,
-                    hash_set![..accessed],
-                    when_same_parent,
-                ))
-            }
-            AccessRule::MayOnlyBeAccessedBy {
-                accessors,
-                accessed,
-                when_same_parent,
-            } => {
-                architecture = architecture.with_access_rule(MayOnlyBeAccessedBy::new(
-                    accessed,
-                    hash_set![..accessors],
-                    when_same_parent,
-                ))
-            }
-            AccessRule::MayNotBeAccessedBy {
-                accessors,
-                accessed,
-                when_same_parent,
-            } => {
-                architecture = architecture.with_access_rule(MayNotBeAccessedBy::new(
-                    accessed,
-                    hash_set![..accessors],
-                    when_same_parent,
-                ))
+/// Reads the specification, on failure every invalid entry is reported at once
+pub fn read_specification(specification_path: &Path) -> Result<Specification, Failure> {
+    let content = read_file_content(specification_path)?;
+    serde_json::from_str(&content).map_err(|error| {
+        if error.is_syntax() || error.is_eof() {
+            return Failure::SpecificationCouldNotBeParsed(vec![invalid_json(&error)]);
+        }
+        let mut problems = serde_json::from_str::<Value>(&content)
+            .map(|value| entry_problems(&value))
+            .unwrap_or_default();
+        if problems.is_empty() {
+            problems.push(invalid_json(&error));
+        }
+        Failure::SpecificationCouldNotBeParsed(problems)
+    })
+}
+
+pub fn build_architecture(specification: Specification) -> Architecture<'static> {
+    let mut architecture = Architecture::new(hash_set![..specification.layer_names]);
+    for rule_entry in specification.access_rules {
+        let (access_rule, severity) = rule_entry.into_parts();
+        architecture = architecture.with_access_rule_severity(
+            convert_access_rule(access_rule),
+            convert_severity(severity),
+        );
+    }
+    architecture
+}
+
+fn invalid_json(error: &serde_json::Error) -> SpecificationProblem {
+    SpecificationProblem::InvalidJson {
+        message: error.to_string(),
+        line: error.line(),
+        column: error.column(),
+    }
+}
+
+fn entry_problems(specification: &Value) -> Vec<SpecificationProblem> {
+    let mut problems = Vec::new();
+    if let Err(error) = serde_json::from_value::<Vec<String>>(
+        specification
+            .get("layer_names")
+            .cloned()
+            .unwrap_or(Value::Null),
+    ) {
+        problems.push(SpecificationProblem::InvalidEntry {
+            location: "layer_names".to_owned(),
+            message: error.to_string(),
+            suggestion: None,
+        });
+    }
+    match specification.get("access_rules") {
+        Some(Value::Array(entries)) => {
+            for (index, entry) in entries.iter().enumerate() {
+                if serde_json::from_value::<RuleEntry>(entry.clone()).is_ok() {
+                    continue;
+                }
+                let rule = match entry.get("rule") {
+                    Some(rule) => rule.clone(),
+                    None => entry.clone(),
+                };
+                let severity = entry.get("severity").cloned().unwrap_or_default();
+                let message = match (
+                    serde_json::from_value::<AccessRule>(rule.clone()),
+                    serde_json::from_value::<Severity>(severity),
+                ) {
+                    (Err(error), _) | (_, Err(error)) => error.to_string(),
+                    _ => "expected a rule or an object with 'rule' and 'severity'".to_owned(),
+                };
+                let rule_name = match &rule {
+                    Value::String(name) => Some(name.clone()),
+                    Value::Object(map) => map.keys().next().cloned(),
+                    _ => None,
+                };
+                problems.push(SpecificationProblem::InvalidEntry {
+                    location: format!("access_rules[{}]", index),
+                    message,
+                    suggestion: rule_name
+                        .filter(|name| !RULE_NAMES.contains(&name.as_str()))
+                        .and_then(|name| closest_match(&name, RULE_NAMES.iter().copied())),
+                });
             }
         }
+        _ => problems.push(SpecificationProblem::InvalidEntry {
+            location: "access_rules".to_owned(),
+            message: "expected a list of rules".to_owned(),
+            suggestion: None,
+        }),
+    }
+    problems
+}
+
+fn convert_severity(severity: Severity) -> CoreSeverity {
+    match severity {
+        Severity::Deny => CoreSeverity::Deny,
+        Severity::Warn => CoreSeverity::Warn,
+        Severity::Allow => CoreSeverity::Allow,
+    }
+}
+
+fn convert_access_rule(access_rule: AccessRule) -> Box<dyn CoreAccessRule> {
+    match access_rule {
+        AccessRule::NoLayerCyclicDependencies => Box::new(NoLayerCyclicDependencies),
+        AccessRule::NoModuleCyclicDependencies => Box::new(NoModuleCyclicDependencies),
+        AccessRule::NoItemCyclicDependencies { scope } => {
+            let scope = match scope {
+                ItemCycleScope::Crate => CoreItemCycleScope::Crate,
+                ItemCycleScope::WithinLayer(layer) => CoreItemCycleScope::WithinLayer(layer),
+                ItemCycleScope::AcrossLayers(layers) => {
+                    CoreItemCycleScope::AcrossLayers(hash_set![..layers])
+                }
+            };
+            Box::new(NoItemCyclicDependencies::new(scope))
+        }
+        AccessRule::NoParentAccess => Box::new(NoParentAccess),
+        AccessRule::MayOnlyAccess {
+            accessor,
+            accessed,
+            when_same_parent,
+        } => Box::new(MayOnlyAccess::new(
+            accessor,
+            hash_set![..accessed],
+            when_same_parent,
+        )),
+        AccessRule::MayNotAccess {
+            accessor,
+            accessed,
+            when_same_parent,
+        } => Box::new(MayNotAccess::new(
+            accessor,
+            hash_set![..accessed],
+            when_same_parent,
+        )),
+        AccessRule::MayOnlyBeAccessedBy {
+            accessors,
+            accessed,
+            when_same_parent,
+        } => Box::new(MayOnlyBeAccessedBy::new(
+            accessed,
+            hash_set![..accessors],
+            when_same_parent,
+        )),
+        AccessRule::MayNotBeAccessedBy {
+            accessors,
+            accessed,
+            when_same_parent,
+        } => Box::new(MayNotBeAccessedBy::new(
+            accessed,
+            hash_set![..accessors],
+            when_same_parent,
+        )),
+        AccessRule::AllOf(rules) => Box::new(AllOf::new(
+            rules.into_iter().map(convert_access_rule).collect(),
+        )),
+        AccessRule::AnyOf(rules) => Box::new(AnyOf::new(
+            rules.into_iter().map(convert_access_rule).collect(),
+        )),
+        AccessRule::Not(rule) => Box::new(Not::new(convert_access_rule(*rule))),
+        AccessRule::Within { scope, rule } => {
+            Box::new(Within::new(scope, convert_access_rule(*rule)))
+        }
     }
-    Ok(architecture)
 }
 
 fn read_file_content(file_path: &Path) -> Result<String, Failure> {
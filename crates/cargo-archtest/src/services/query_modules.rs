@@ -0,0 +1,82 @@
+use arch_test_core::ModuleTree;
+
+use crate::domain_values::{CheckOptions, CheckResult, QueryCommand};
+use crate::services::{closest_match, load_architecture, print_message};
+
+/// Answers the query about a module of the project, one module or item path per line
+///
+/// Lists the dependencies of the module by default. Fails with the errors of loading the architecture or if a module does not exist.
+pub fn query_modules(
+    directory_path: &str,
+    options: &CheckOptions,
+    query_command: &QueryCommand,
+) -> Result<String, CheckResult> {
+    let mut result = CheckResult::default();
+    let module_tree = match load_architecture(directory_path, options, &mut result) {
+        Some((_, module_tree)) => module_tree,
+        None => return Err(result),
+    };
+    let module = find_module(&module_tree, &query_command.module, options, &mut result)?;
+    let lines: Vec<String> = if let Some(target) = query_command.path_to.as_ref() {
+        let target = find_module(&module_tree, target, options, &mut result)?;
+        match module_tree.path_between(module, target) {
+            Some(path) => vec![path
+                .into_iter()
+                .map(|index| module_path(&module_tree, index))
+                .collect::<Vec<String>>()
+                .join(" -> ")],
+            None => {
+                print_message(
+                    options,
+                    &format!(
+                        "[None]: '{}' does not depend on '{}'.",
+                        query_command.module,
+                        query_command.path_to.as_ref().unwrap()
+                    ),
+                );
+                vec![]
+            }
+        }
+    } else if query_command.items {
+        module_tree
+            .items_in(module)
+            .map(|item| item.full_module_path().clone())
+            .collect()
+    } else {
+        let modules = if query_command.dependents {
+            module_tree.dependents_of(module, query_command.transitive)
+        } else {
+            module_tree.dependencies_of(module, query_command.transitive)
+        };
+        modules
+            .into_iter()
+            .map(|index| module_path(&module_tree, index))
+            .collect()
+    };
+    Ok(lines.into_iter().map(|line| line + "\n").collect())
+}
+
+fn find_module(
+    module_tree: &ModuleTree,
+    path: &str,
+    options: &CheckOptions,
+    result: &mut CheckResult,
+) -> Result<usize, CheckResult> {
+    module_tree.find_module(path).ok_or_else(|| {
+        let module_paths: Vec<String> = (0..module_tree.tree().len())
+            .map(|index| module_path(module_tree, index))
+            .collect();
+        let suggestion = closest_match(path, module_paths.iter().map(|path| path.as_str()));
+        let mut message = format!("[Error]: Module '{}' does not exist.", path);
+        if let Some(suggestion) = suggestion {
+            message.push_str(&format!(" Did you mean '{}'?", suggestion));
+        }
+        print_message(options, &message);
+        result.errors += 1;
+        std::mem::take(result)
+    })
+}
+
+fn module_path(module_tree: &ModuleTree, index: usize) -> String {
+    module_tree.tree()[index].get_fully_qualified_path(module_tree.tree())
+}
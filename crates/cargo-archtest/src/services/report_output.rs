@@ -0,0 +1,90 @@
+use std::io::IsTerminal;
+
+use arch_test_core::{DiagnosticStyle, Severity};
+use serde_json::{json, Value};
+
+use crate::domain_values::{CheckOptions, ColorChoice, OutputFormat};
+use crate::services::{github_annotations, gitlab_code_quality, junit_report, sarif_report};
+
+/// Prints human readable messages, to stderr if the findings are printed in a machine readable format
+pub fn print_message(options: &CheckOptions, message: &str) {
+    if options.format.is_text() {
+        println!("{}", message);
+    } else {
+        eprintln!("{}", message);
+    }
+}
+
+/// Style of the diagnostics of a rule with the severity, coloured as chosen with `--color`
+pub fn diagnostic_style(options: &CheckOptions, severity: Severity) -> DiagnosticStyle {
+    let color = match options.color {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => {
+            let is_terminal = if options.format.is_text() {
+                std::io::stdout().is_terminal()
+            } else {
+                std::io::stderr().is_terminal()
+            };
+            is_terminal && std::env::var_os("NO_COLOR").is_none()
+        }
+    };
+    DiagnosticStyle::new(severity, color)
+}
+
+/// Combines the reports of all workspace members into one report of the same schema
+///
+/// The rule indices of the violations are shifted to point to the combined rules.
+pub fn merge_reports(reports: &[Value]) -> Value {
+    let mut merged = json!({
+        "schema_version": arch_test_core::REPORT_SCHEMA_VERSION,
+        "rules": [],
+        "violations": [],
+        "warnings": [],
+        "suppressed_violations": [],
+        "unused_suppressions": [],
+    });
+    for report in reports.iter() {
+        let rule_offset = merged["rules"].as_array().map_or(0, |rules| rules.len());
+        for (key, value) in report.as_object().into_iter().flatten() {
+            let values = match value.as_array() {
+                Some(values) => values,
+                None => continue,
+            };
+            let merged_values = merged
+                .as_object_mut()
+                .unwrap()
+                .entry(key.clone())
+                .or_insert_with(|| json!([]));
+            if let Some(merged_values) = merged_values.as_array_mut() {
+                merged_values.extend(values.iter().cloned().map(|mut value| {
+                    if let Some(rule_index) = value["rule_index"].as_u64() {
+                        value["rule_index"] = json!(rule_index as usize + rule_offset);
+                    }
+                    value
+                }));
+            }
+        }
+    }
+    merged
+}
+
+/// The findings of all projects in the requested format, `None` for text as it is printed while checking
+pub fn render_reports(format: OutputFormat, reports: &[Value]) -> Option<String> {
+    match format {
+        OutputFormat::Text => None,
+        OutputFormat::Json => {
+            Some(serde_json::to_string_pretty(&merge_reports(reports)).unwrap_or_default())
+        }
+        OutputFormat::Sarif => Some(
+            serde_json::to_string_pretty(&sarif_report(&merge_reports(reports)))
+                .unwrap_or_default(),
+        ),
+        OutputFormat::Junit => Some(junit_report(&merge_reports(reports))),
+        OutputFormat::Github => Some(github_annotations(&merge_reports(reports))),
+        OutputFormat::Gitlab => Some(
+            serde_json::to_string_pretty(&gitlab_code_quality(&merge_reports(reports)))
+                .unwrap_or_default(),
+        ),
+    }
+}
@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+/// Fingerprints of the violations of a report, stable across runs and platforms
+///
+/// A fingerprint is derived from the rule, its parameters and the item paths of the accessor and the accessed item,
+/// like the baseline, so it does not change when unrelated code moves. Repeated violations are numbered by their occurrence.
+#[derive(Default)]
+pub struct OccurrenceFingerprints {
+    occurrences: HashMap<String, usize>,
+}
+
+impl OccurrenceFingerprints {
+    /// Fingerprint of the next occurrence of the violation, `detail` tells apart parts of it, e.g. its modules
+    pub fn next(&mut self, violation: &Value, detail: Option<&str>) -> String {
+        let mut identity = identity_of(violation);
+        if let Some(detail) = detail {
+            identity = format!("{}|{}", identity, detail);
+        }
+        let occurrence = self.occurrences.entry(identity.clone()).or_insert(0);
+        *occurrence += 1;
+        if *occurrence == 1 {
+            hash_of(&identity)
+        } else {
+            hash_of(&format!("{}|{}", identity, occurrence))
+        }
+    }
+}
+
+/// Values of a JSON array, none for other values
+pub fn array(value: &Value) -> &[Value] {
+    value.as_array().map_or(&[], |values| values.as_slice())
+}
+
+/// Relative file path with forward slashes
+pub fn path_of(file: &Value) -> String {
+    file.as_str()
+        .unwrap_or_default()
+        .replace('\\', "/")
+        .trim_start_matches("./")
+        .to_owned()
+}
+
+/// Name the violation is reported with, falling back to the name of its rule
+pub fn rule_name_of(violation: &Value) -> &str {
+    violation["rule_name"]
+        .as_str()
+        .or_else(|| violation["rule"]["name"].as_str())
+        .unwrap_or_default()
+}
+
+fn identity_of(violation: &Value) -> String {
+    let fingerprint = &violation["fingerprint"];
+    format!(
+        "{}|{}|{}|{}",
+        fingerprint["rule"]
+            .as_str()
+            .unwrap_or_else(|| rule_name_of(violation)),
+        fingerprint["parameters"].as_str().unwrap_or_default(),
+        fingerprint["accessor"].as_str().unwrap_or_default(),
+        fingerprint["accessed"].as_str().unwrap_or_default()
+    )
+}
+
+/// 128 bit FNV-1a hash as hex string
+fn hash_of(identity: &str) -> String {
+    const OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
+    const PRIME: u128 = 0x0000000001000000000000000000013B;
+    let hash = identity.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u128::from(byte)).wrapping_mul(PRIME)
+    });
+    format!("{:032x}", hash)
+}
@@ -0,0 +1,190 @@
+use serde_json::{json, Value};
+
+use crate::services::report_values::{array, path_of, OccurrenceFingerprints};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Converts a report of the JSON schema of `Report::to_json` into a SARIF 2.1.0 log
+///
+/// Every configured access rule becomes a rule descriptor, violations without a rule,
+/// e.g. of the complete layer specification, get a descriptor per `RuleViolationType`.
+/// Violations are reported at the accessor with the accessed item and every hop of a cycle as related locations.
+/// Results are identified like the issues of the GitLab Code Quality report, see `OccurrenceFingerprints`.
+pub fn sarif_report(report: &Value) -> Value {
+    let mut rule_ids: Vec<String> = Vec::new();
+    let mut descriptors: Vec<Value> = Vec::new();
+    for rule in array(&report["rules"]) {
+        let name = rule["name"].as_str().unwrap_or_default();
+        let occurrences = rule_ids
+            .iter()
+            .filter(|id| id.as_str() == name || id.starts_with(&format!("{}.", name)))
+            .count();
+        let id = if occurrences == 0 {
+            name.to_owned()
+        } else {
+            format!("{}.{}", name, occurrences + 1)
+        };
+        descriptors.push(json!({
+            "id": id,
+            "name": name,
+            "shortDescription": { "text": rule["description"] },
+            "defaultConfiguration": { "level": level_of_severity(rule["severity"].as_str()) },
+            "properties": { "parameters": rule["parameters"] },
+        }));
+        rule_ids.push(id);
+    }
+
+    let mut results = Vec::new();
+    let mut fingerprints = OccurrenceFingerprints::default();
+    for (key, level, baseline_state) in [
+        ("violations", "error", None),
+        ("warnings", "warning", None),
+        ("suppressed_violations", "error", None),
+        ("baselined_violations", "note", Some("unchanged")),
+    ] {
+        for violation in array(&report[key]) {
+            let rule_index = match violation["rule_index"].as_u64() {
+                Some(rule_index) => rule_index as usize,
+                None => violation_type_descriptor(&mut descriptors, &mut rule_ids, violation),
+            };
+            let mut result = result_of(violation, &rule_ids[rule_index], rule_index, level);
+            result["partialFingerprints"] =
+                json!({ "archtest/v1": fingerprints.next(violation, None) });
+            if let Some(suppression) = violation.get("suppression") {
+                result["suppressions"] = json!([{
+                    "kind": "inSource",
+                    "justification": suppression["reason"],
+                }]);
+            }
+            if let Some(baseline_state) = baseline_state {
+                result["baselineState"] = json!(baseline_state);
+            }
+            results.push(result);
+        }
+    }
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "cargo-archtest",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://github.com/Geigerkind/arch_test",
+                    "rules": descriptors,
+                }
+            },
+            "columnKind": "unicodeCodePoints",
+            "results": results,
+        }]
+    })
+}
+
+/// Index of the descriptor of the violation type, added on first use
+fn violation_type_descriptor(
+    descriptors: &mut Vec<Value>,
+    rule_ids: &mut Vec<String>,
+    violation: &Value,
+) -> usize {
+    let violation_type = violation["violation_type"].as_str().unwrap_or_default();
+    if let Some(index) = rule_ids.iter().position(|id| id == violation_type) {
+        return index;
+    }
+    descriptors.push(json!({
+        "id": violation_type,
+        "name": violation_type,
+        "shortDescription": { "text": violation["rule"]["description"] },
+        "defaultConfiguration": { "level": "error" },
+    }));
+    rule_ids.push(violation_type.to_owned());
+    rule_ids.len() - 1
+}
+
+fn result_of(violation: &Value, rule_id: &str, rule_index: usize, level: &str) -> Value {
+    let mut locations: Vec<Value> = violation
+        .get("accessor")
+        .filter(|accessor| !accessor.is_null())
+        .map(|accessor| physical_location(accessor, None))
+        .into_iter()
+        .collect();
+    locations.extend(array(&violation["modules"]).iter().map(|module| {
+        json!({
+            "physicalLocation": { "artifactLocation": artifact_location(&module["file"]) },
+            "message": { "text": module["module_path"] },
+        })
+    }));
+
+    let mut related_locations = Vec::new();
+    let accessed = &violation["accessed"];
+    if !accessed.is_null() {
+        related_locations.push(physical_location(
+            accessed,
+            Some(format!(
+                "Accessed {}",
+                accessed["item_path"].as_str().unwrap_or_default()
+            )),
+        ));
+    }
+    if violation["violation_type"] == "Cycle" {
+        for use_relation in array(&violation["uses"]) {
+            related_locations.push(physical_location(
+                &use_relation["accessor"],
+                Some(format!(
+                    "{} uses {}",
+                    use_relation["accessor"]["item_path"]
+                        .as_str()
+                        .unwrap_or_default(),
+                    use_relation["accessed"]["item_path"]
+                        .as_str()
+                        .unwrap_or_default()
+                )),
+            ));
+        }
+    }
+    for (id, related_location) in related_locations.iter_mut().enumerate() {
+        related_location["id"] = json!(id);
+    }
+
+    json!({
+        "ruleId": rule_id,
+        "ruleIndex": rule_index,
+        "level": level,
+        "message": { "text": violation["message"] },
+        "locations": locations,
+        "relatedLocations": related_locations,
+        "properties": { "violationType": violation["violation_type"] },
+    })
+}
+
+fn physical_location(location: &Value, message: Option<String>) -> Value {
+    let mut physical_location = json!({
+        "physicalLocation": {
+            "artifactLocation": artifact_location(&location["file"]),
+            "region": {
+                "startLine": location["line"],
+                "startColumn": location["column"],
+                "endLine": location["end_line"],
+                "endColumn": location["end_column"],
+                "snippet": { "text": location["snippet"] },
+            }
+        }
+    });
+    if let Some(message) = message {
+        physical_location["message"] = json!({ "text": message });
+    }
+    physical_location
+}
+
+/// Relative file path, resolved against the source root
+fn artifact_location(file: &Value) -> Value {
+    json!({ "uri": path_of(file), "uriBaseId": "%SRCROOT%" })
+}
+
+fn level_of_severity(severity: Option<&str>) -> &'static str {
+    match severity {
+        Some("warn") => "warning",
+        Some("allow") => "none",
+        _ => "error",
+    }
+}
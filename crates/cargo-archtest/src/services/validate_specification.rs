@@ -0,0 +1,246 @@
+use std::collections::HashSet;
+
+use arch_test_core::ModuleTree;
+
+use crate::domain_values::{
+    AccessRule, ItemCycleScope, Severity, Specification, SpecificationProblem,
+};
+use crate::services::closest_match;
+
+/// Finds every problem of a specification that was parsed successfully
+///
+/// These are layers missing in `layer_names`, layers without any module in the module tree,
+/// module paths used outside of the scope of `Within`
+/// and rules that allow and forbid the same access with the same `when_same_parent`.
+pub fn validate_specification(
+    specification: &Specification,
+    module_tree: &ModuleTree,
+) -> Vec<SpecificationProblem> {
+    let mut problems = Vec::new();
+    let mut allowed = Vec::new();
+    let mut forbidden = Vec::new();
+    for (index, rule_entry) in specification.access_rules.iter().enumerate() {
+        let (access_rule, severity) = rule_entry.clone().into_parts();
+        let location = format!("access_rules[{}]", index);
+        check_layers(
+            &access_rule,
+            &location,
+            specification,
+            module_tree,
+            &mut problems,
+        );
+        if !matches!(severity, Severity::Allow) {
+            collect_accesses(&access_rule, &location, &mut allowed, &mut forbidden);
+        }
+    }
+
+    let mut reported = HashSet::new();
+    for (location, access) in allowed.iter() {
+        if let Some((other_location, _)) = forbidden
+            .iter()
+            .find(|(_, other_access)| other_access == access)
+        {
+            if reported.insert((location, other_location)) {
+                problems.push(SpecificationProblem::ContradictingRules {
+                    location: location.clone(),
+                    other_location: other_location.clone(),
+                    accessor: access.accessor.clone(),
+                    accessed: access.accessed.clone(),
+                });
+            }
+        }
+    }
+    problems
+}
+
+fn check_layers(
+    access_rule: &AccessRule,
+    location: &str,
+    specification: &Specification,
+    module_tree: &ModuleTree,
+    problems: &mut Vec<SpecificationProblem>,
+) {
+    let graph = module_tree.graph_view();
+    let mut checked = HashSet::new();
+    for layer in layers_of(access_rule) {
+        if !checked.insert(layer.clone()) {
+            continue;
+        }
+        let is_module_path = layer.starts_with("crate::") || layer == "crate";
+        if is_module_path && !matches!(access_rule, AccessRule::Within { .. }) {
+            problems.push(SpecificationProblem::ModulePathNotSupported {
+                location: location.to_owned(),
+                path: layer,
+            });
+        } else if is_module_path {
+            let submodule_prefix = format!("{}::", layer);
+            if !graph.modules().any(|node| {
+                let module_path = graph.module_path(node.index());
+                module_path == layer || module_path.starts_with(&submodule_prefix)
+            }) {
+                problems.push(SpecificationProblem::NeverMatches {
+                    location: location.to_owned(),
+                    layer,
+                });
+            }
+        } else if !specification.layer_names.contains(&layer) {
+            problems.push(SpecificationProblem::UnknownLayer {
+                location: location.to_owned(),
+                suggestion: closest_match(
+                    &layer,
+                    specification.layer_names.iter().map(|name| name.as_str()),
+                ),
+                layer,
+            });
+        } else if graph.modules_in_layer(&layer).next().is_none() {
+            problems.push(SpecificationProblem::NeverMatches {
+                location: location.to_owned(),
+                layer,
+            });
+        }
+    }
+    match access_rule {
+        AccessRule::AllOf(rules) | AccessRule::AnyOf(rules) => {
+            for (index, rule) in rules.iter().enumerate() {
+                let name = if matches!(access_rule, AccessRule::AllOf(_)) {
+                    "AllOf"
+                } else {
+                    "AnyOf"
+                };
+                check_layers(
+                    rule,
+                    &format!("{}.{}[{}]", location, name, index),
+                    specification,
+                    module_tree,
+                    problems,
+                );
+            }
+        }
+        AccessRule::Not(rule) => check_layers(
+            rule,
+            &format!("{}.Not", location),
+            specification,
+            module_tree,
+            problems,
+        ),
+        AccessRule::Within { rule, .. } => check_layers(
+            rule,
+            &format!("{}.Within", location),
+            specification,
+            module_tree,
+            problems,
+        ),
+        _ => {}
+    }
+}
+
+/// Layers referenced by the rule itself, layers of nested rules are not included
+fn layers_of(access_rule: &AccessRule) -> Vec<String> {
+    match access_rule {
+        AccessRule::NoItemCyclicDependencies { scope } => match scope {
+            ItemCycleScope::Crate => vec![],
+            ItemCycleScope::WithinLayer(layer) => vec![layer.clone()],
+            ItemCycleScope::AcrossLayers(layers) => layers.clone(),
+        },
+        AccessRule::MayOnlyAccess {
+            accessor, accessed, ..
+        }
+        | AccessRule::MayNotAccess {
+            accessor, accessed, ..
+        } => std::iter::once(accessor)
+            .chain(accessed.iter())
+            .cloned()
+            .collect(),
+        AccessRule::MayOnlyBeAccessedBy {
+            accessors,
+            accessed,
+            ..
+        }
+        | AccessRule::MayNotBeAccessedBy {
+            accessors,
+            accessed,
+            ..
+        } => std::iter::once(accessed)
+            .chain(accessors.iter())
+            .cloned()
+            .collect(),
+        AccessRule::Within { scope, .. } => vec![scope.clone()],
+        _ => vec![],
+    }
+}
+
+/// An access of the accessor layer to the accessed layer as stated by a rule
+#[derive(PartialEq)]
+struct Access {
+    accessor: String,
+    accessed: String,
+    when_same_parent: bool,
+}
+
+/// Accesses explicitly allowed and forbidden by the rule, `AllOf` keeps the meaning of its rules
+fn collect_accesses(
+    access_rule: &AccessRule,
+    location: &str,
+    allowed: &mut Vec<(String, Access)>,
+    forbidden: &mut Vec<(String, Access)>,
+) {
+    let access = |accessor: &String, accessed: &String, when_same_parent: &bool| {
+        (
+            location.to_owned(),
+            Access {
+                accessor: accessor.clone(),
+                accessed: accessed.clone(),
+                when_same_parent: *when_same_parent,
+            },
+        )
+    };
+    match access_rule {
+        AccessRule::MayOnlyAccess {
+            accessor,
+            accessed,
+            when_same_parent,
+        } => {
+            for layer in accessed.iter() {
+                allowed.push(access(accessor, layer, when_same_parent));
+            }
+        }
+        AccessRule::MayNotAccess {
+            accessor,
+            accessed,
+            when_same_parent,
+        } => {
+            for layer in accessed.iter() {
+                forbidden.push(access(accessor, layer, when_same_parent));
+            }
+        }
+        AccessRule::MayOnlyBeAccessedBy {
+            accessors,
+            accessed,
+            when_same_parent,
+        } => {
+            for layer in accessors.iter() {
+                allowed.push(access(layer, accessed, when_same_parent));
+            }
+        }
+        AccessRule::MayNotBeAccessedBy {
+            accessors,
+            accessed,
+            when_same_parent,
+        } => {
+            for layer in accessors.iter() {
+                forbidden.push(access(layer, accessed, when_same_parent));
+            }
+        }
+        AccessRule::AllOf(rules) => {
+            for (index, rule) in rules.iter().enumerate() {
+                collect_accesses(
+                    rule,
+                    &format!("{}.AllOf[{}]", location, index),
+                    allowed,
+                    forbidden,
+                );
+            }
+        }
+        _ => {}
+    }
+}
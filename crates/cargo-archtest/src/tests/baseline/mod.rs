@@ -0,0 +1,112 @@
+use std::path::Path;
+
+use arch_test_core::ViolationFingerprint;
+
+use crate::domain_values::{Baseline, BaselineEntry};
+use crate::services::{compare_with_baseline, read_baseline, write_baseline};
+
+fn fingerprint(
+    rule: &str,
+    parameters: &str,
+    accessor: &str,
+    accessed: &str,
+) -> ViolationFingerprint {
+    ViolationFingerprint::new(
+        rule.to_owned(),
+        parameters.to_owned(),
+        accessor.to_owned(),
+        accessed.to_owned(),
+    )
+}
+
+#[test]
+fn compare() {
+    let baseline = Baseline::new(&[
+        fingerprint(
+            "MayNotAccess",
+            "accessor = parser",
+            "crate::parser",
+            "crate::analyzer::Architecture",
+        ),
+        fingerprint(
+            "NoParentAccess",
+            "",
+            "crate::parser::services",
+            "crate::parser::Foo",
+        ),
+    ]);
+    let comparison = compare_with_baseline(
+        &[
+            fingerprint(
+                "MayNotAccess",
+                "accessor = parser",
+                "crate::parser",
+                "crate::analyzer::Architecture",
+            ),
+            fingerprint(
+                "MayNotAccess",
+                "accessor = parser",
+                "crate::parser::fun",
+                "crate::analyzer::Architecture",
+            ),
+            fingerprint(
+                "MayNotAccess",
+                "accessor = services",
+                "crate::parser",
+                "crate::analyzer::Architecture",
+            ),
+        ],
+        &baseline,
+    );
+    assert_eq!(comparison.new_violations, vec![1, 2]);
+    assert_eq!(
+        comparison.stale_entries,
+        vec![BaselineEntry {
+            rule: "NoParentAccess".to_owned(),
+            parameters: String::new(),
+            accessor: "crate::parser::services".to_owned(),
+            accessed: "crate::parser::Foo".to_owned(),
+            count: 1,
+        }]
+    );
+}
+
+#[test]
+fn compare_occurrences() {
+    let violation = fingerprint("MayNotAccess", "accessor = a", "crate::a", "crate::Bar");
+    let baseline = Baseline::new(&[violation.clone(), violation.clone()]);
+    assert_eq!(baseline.violations.len(), 1);
+    assert_eq!(baseline.accepted_violations(), 2);
+
+    let comparison = compare_with_baseline(
+        &[violation.clone(), violation.clone(), violation.clone()],
+        &baseline,
+    );
+    assert_eq!(comparison.new_violations, vec![2]);
+    assert!(comparison.stale_entries.is_empty());
+
+    let comparison = compare_with_baseline(&[violation], &baseline);
+    assert!(comparison.new_violations.is_empty());
+    assert_eq!(comparison.stale_entries[0].count, 1);
+}
+
+#[test]
+fn write_and_read() {
+    let baseline = Baseline::new(&[
+        fingerprint("NoParentAccess", "", "crate::b", "crate::Foo"),
+        fingerprint("MayNotAccess", "accessor = a", "crate::a", "crate::Bar"),
+        fingerprint("MayNotAccess", "accessor = a", "crate::a", "crate::Bar"),
+        fingerprint("MayNotAccess", "accessor = c", "crate::a", "crate::Bar"),
+    ]);
+    assert_eq!(baseline.violations.len(), 3);
+    assert_eq!(baseline.accepted_violations(), 4);
+    assert_eq!(baseline.violations[0].rule, "MayNotAccess");
+    assert_eq!(baseline.violations[0].count, 2);
+
+    let baseline_path = std::env::temp_dir().join("archtest-baseline-write-and-read.json");
+    write_baseline(&baseline_path, &baseline).unwrap();
+    assert_eq!(read_baseline(&baseline_path).unwrap(), baseline);
+    std::fs::remove_file(&baseline_path).unwrap();
+
+    assert!(read_baseline(Path::new("src/tests/baseline/missing.json")).is_err());
+}
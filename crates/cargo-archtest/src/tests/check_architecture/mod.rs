@@ -1,6 +1,281 @@
-use crate::services::check_architecture;
+use serde_json::{json, Value};
+
+use crate::domain_values::{CheckOptions, CheckResult, OutputFormat};
+use crate::services::{check_architecture, render_reports};
 
 #[test]
 fn run_check_architecture() {
-    check_architecture("src/tests/check_architecture/test_architecture", true);
+    let result = check_architecture(
+        "src/tests/check_architecture/test_architecture",
+        &CheckOptions {
+            check_for_complete_layer_specification: true,
+            ..CheckOptions::default()
+        },
+    );
+    assert_eq!(result, CheckResult::default());
+}
+
+#[test]
+fn severities() {
+    let directory_path = "src/tests/check_architecture/severities";
+    assert_eq!(
+        check_architecture(directory_path, &CheckOptions::default()),
+        CheckResult {
+            errors: 0,
+            warnings: 1,
+            ..CheckResult::default()
+        }
+    );
+    assert_eq!(
+        check_architecture(
+            directory_path,
+            &CheckOptions {
+                deny_warnings: true,
+                ..CheckOptions::default()
+            }
+        ),
+        CheckResult {
+            errors: 1,
+            warnings: 0,
+            ..CheckResult::default()
+        }
+    );
+    assert_eq!(
+        check_architecture(
+            directory_path,
+            &CheckOptions {
+                deny: vec!["NoParentAccess".to_owned()],
+                ..CheckOptions::default()
+            }
+        ),
+        CheckResult {
+            errors: 1,
+            warnings: 0,
+            ..CheckResult::default()
+        }
+    );
+    assert_eq!(
+        check_architecture(
+            directory_path,
+            &CheckOptions {
+                allow: vec!["NoParentAccess".to_owned()],
+                ..CheckOptions::default()
+            }
+        ),
+        CheckResult::default()
+    );
+}
+
+#[test]
+fn json_report() {
+    let result = check_architecture(
+        "src/tests/check_architecture/severities",
+        &CheckOptions {
+            deny_warnings: true,
+            format: OutputFormat::Json,
+            ..CheckOptions::default()
+        },
+    );
+    assert_eq!(result.errors, 1);
+    let output: Value =
+        serde_json::from_str(&render_reports(OutputFormat::Json, &result.reports).unwrap())
+            .unwrap();
+    assert_eq!(output["schema_version"], 1);
+    assert_eq!(output["rules"].as_array().unwrap().len(), 2);
+    let violation = &output["violations"][0];
+    assert_eq!(violation["rule_name"], "NoParentAccess");
+    assert_eq!(violation["rule_index"], 0);
+    assert_eq!(violation["violation_type"], "SingleLocation");
+    assert_eq!(
+        violation["accessor"]["file"],
+        "src/tests/check_architecture/severities/src/child.rs"
+    );
+    assert!(violation["accessor"]["line"].as_u64().unwrap() > 0);
+}
+
+#[test]
+fn sarif_report() {
+    let result = check_architecture(
+        "src/tests/check_architecture/severities",
+        &CheckOptions {
+            format: OutputFormat::Sarif,
+            ..CheckOptions::default()
+        },
+    );
+    let output: Value =
+        serde_json::from_str(&render_reports(OutputFormat::Sarif, &result.reports).unwrap())
+            .unwrap();
+    assert_eq!(output["version"], "2.1.0");
+    let run = &output["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "cargo-archtest");
+    assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "NoParentAccess");
+    assert_eq!(
+        run["tool"]["driver"]["rules"][0]["defaultConfiguration"]["level"],
+        "warning"
+    );
+    let sarif_result = &run["results"][0];
+    assert_eq!(sarif_result["ruleId"], "NoParentAccess");
+    assert_eq!(sarif_result["ruleIndex"], 0);
+    assert_eq!(sarif_result["level"], "warning");
+    let location = &sarif_result["locations"][0]["physicalLocation"];
+    assert_eq!(
+        location["artifactLocation"]["uri"],
+        "src/tests/check_architecture/severities/src/child.rs"
+    );
+    assert!(location["region"]["startLine"].as_u64().unwrap() > 0);
+    assert!(location["region"]["startColumn"].as_u64().unwrap() > 0);
+    assert_eq!(
+        sarif_result["relatedLocations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+        "src/tests/check_architecture/severities/src/main.rs"
+    );
+}
+
+#[test]
+fn sarif_report_of_cycle() {
+    let location = |file: &str, item_path: &str| json!({ "file": file, "line": 1, "column": 5, "end_line": 1, "end_column": 9, "item_path": item_path });
+    let report = json!({
+        "rules": [],
+        "violations": [{
+            "rule_index": null,
+            "rule": { "name": "NoModuleCyclicDependencies", "description": "No cycles" },
+            "violation_type": "Cycle",
+            "message": "cycle",
+            "accessor": location("./src/first.rs", "crate::first::One"),
+            "accessed": location("./src/second.rs", "crate::second::Two"),
+            "uses": [
+                { "accessor": location("./src/first.rs", "crate::first::One"), "accessed": location("./src/second.rs", "crate::second::Two") },
+                { "accessor": location("./src/second.rs", "crate::second::Two"), "accessed": location("./src/first.rs", "crate::first::One") },
+            ],
+            "modules": [],
+            "fingerprint": { "rule": "NoModuleCyclicDependencies", "accessor": "crate::first::One", "accessed": "crate::second::Two" },
+        }],
+    });
+    let output = crate::services::sarif_report(&report);
+    let run = &output["runs"][0];
+    assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "Cycle");
+    let related_locations = run["results"][0]["relatedLocations"].as_array().unwrap();
+    assert_eq!(related_locations.len(), 3);
+    assert_eq!(
+        related_locations[2]["physicalLocation"]["artifactLocation"]["uri"],
+        "src/second.rs"
+    );
+    assert_eq!(
+        related_locations[2]["message"]["text"],
+        "crate::second::Two uses crate::first::One"
+    );
+}
+
+#[test]
+fn junit_report() {
+    let result = check_architecture(
+        "src/tests/check_architecture/severities",
+        &CheckOptions {
+            deny_warnings: true,
+            format: OutputFormat::Junit,
+            ..CheckOptions::default()
+        },
+    );
+    let output = render_reports(OutputFormat::Junit, &result.reports).unwrap();
+    assert!(output.starts_with("<?xml"));
+    assert!(output.contains("<testsuite name=\"architecture\" tests=\"2\" failures=\"1\""));
+    assert!(output.contains("<testcase classname=\"archtest\" name=\"NoParentAccess\">"));
+    assert!(output.contains("<failure message=\"[NoParentAccess]"));
+    assert!(output.contains("accessor crate::child"));
+    assert!(output.contains("src/tests/check_architecture/severities/src/child.rs:"));
+    assert_eq!(output.matches("<failure ").count(), 1);
+}
+
+#[test]
+fn github_annotations() {
+    let result = check_architecture(
+        "src/tests/check_architecture/severities",
+        &CheckOptions {
+            format: OutputFormat::Github,
+            ..CheckOptions::default()
+        },
+    );
+    let output = render_reports(OutputFormat::Github, &result.reports).unwrap();
+    assert_eq!(output.lines().count(), 1);
+    assert!(output
+        .starts_with("::warning file=src/tests/check_architecture/severities/src/child.rs,line="));
+    assert!(output.contains(",title=NoParentAccess::[NoParentAccess]"));
+}
+
+#[test]
+fn gitlab_code_quality() {
+    let options = CheckOptions {
+        deny_warnings: true,
+        format: OutputFormat::Gitlab,
+        ..CheckOptions::default()
+    };
+    let result = check_architecture("src/tests/check_architecture/severities", &options);
+    let output: Value =
+        serde_json::from_str(&render_reports(OutputFormat::Gitlab, &result.reports).unwrap())
+            .unwrap();
+    let issue = &output[0];
+    assert_eq!(issue["check_name"], "NoParentAccess");
+    assert_eq!(issue["severity"], "major");
+    assert_eq!(
+        issue["location"]["path"],
+        "src/tests/check_architecture/severities/src/child.rs"
+    );
+    assert!(
+        issue["location"]["positions"]["begin"]["line"]
+            .as_u64()
+            .unwrap()
+            > 0
+    );
+    assert_eq!(issue["fingerprint"].as_str().unwrap().len(), 32);
+
+    let again = check_architecture("src/tests/check_architecture/severities", &options);
+    let again: Value =
+        serde_json::from_str(&render_reports(OutputFormat::Gitlab, &again.reports).unwrap())
+            .unwrap();
+    assert_eq!(again[0]["fingerprint"], issue["fingerprint"]);
+}
+
+#[test]
+fn fingerprints_are_unique() {
+    let location =
+        json!({ "file": "./src/child.rs", "line": 1, "column": 5, "end_line": 1, "end_column": 9 });
+    let violation = |parameters: &str| {
+        json!({
+            "rule": { "name": "MayNotAccess" },
+            "message": "forbidden",
+            "accessor": location,
+            "modules": [],
+            "fingerprint": { "rule": "MayNotAccess", "parameters": parameters, "accessor": "crate::child", "accessed": "crate::Parent" },
+        })
+    };
+    let report = json!({
+        "violations": [
+            violation("accessor = child, accessed = [parent]"),
+            violation("accessor = child, accessed = [parent, other]"),
+            violation("accessor = child, accessed = [parent]"),
+        ],
+    });
+    let output = crate::services::gitlab_code_quality(&report);
+    let fingerprints: Vec<&str> = output
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|issue| issue["fingerprint"].as_str().unwrap())
+        .collect();
+    assert_eq!(fingerprints.len(), 3);
+    assert_ne!(fingerprints[0], fingerprints[1]);
+    assert_ne!(fingerprints[0], fingerprints[2]);
+    assert_ne!(fingerprints[1], fingerprints[2]);
+
+    let output = crate::services::sarif_report(&report);
+    let sarif_fingerprints: Vec<&str> = output["runs"][0]["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|result| {
+            result["partialFingerprints"]["archtest/v1"]
+                .as_str()
+                .unwrap()
+        })
+        .collect();
+    assert_eq!(sarif_fingerprints, fingerprints);
 }
@@ -0,0 +1,5 @@
+use crate::parent_exec;
+
+pub fn child_exec() {
+    parent_exec();
+}
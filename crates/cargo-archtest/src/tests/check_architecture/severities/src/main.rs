@@ -0,0 +1,5 @@
+mod child;
+
+pub fn parent_exec() {}
+
+fn main() {}
@@ -0,0 +1,46 @@
+use arch_test_core::{ModuleTree, MODULE_TREE_SCHEMA_VERSION};
+
+use crate::domain_values::{CheckOptions, DumpCommand};
+use crate::services::dump_model;
+
+#[test]
+fn json_dump() {
+    let model = dump_model(
+        "src/tests/check_architecture/severities",
+        &CheckOptions::default(),
+        &DumpCommand::default(),
+    )
+    .unwrap();
+    let json: serde_json::Value = serde_json::from_str(&model).unwrap();
+    assert_eq!(json["schema_version"], MODULE_TREE_SCHEMA_VERSION);
+    assert_eq!(json["tree"][1]["module_name"], "child");
+    assert_eq!(
+        json["possible_uses"]["crate::parent_exec"]["usable_object"]["object_type"],
+        "Function"
+    );
+
+    let module_tree: ModuleTree = serde_json::from_str(&model).unwrap();
+    assert_eq!(module_tree.tree().len(), 2);
+    assert_eq!(module_tree.graph_view().dependencies().count(), 1);
+}
+
+#[test]
+fn dump_without_specification() {
+    let model = dump_model(
+        "src/tests/dump_model/without_specification",
+        &CheckOptions::default(),
+        &DumpCommand::default(),
+    )
+    .unwrap();
+    let json: serde_json::Value = serde_json::from_str(&model).unwrap();
+    assert_eq!(json["tree"][1]["module_name"], "store");
+    assert!(json["tree"][0].get("line_index").is_none());
+
+    let result = dump_model(
+        "src/tests/dump_model/missing",
+        &CheckOptions::default(),
+        &DumpCommand::default(),
+    )
+    .unwrap_err();
+    assert_eq!(result.errors, 1);
+}
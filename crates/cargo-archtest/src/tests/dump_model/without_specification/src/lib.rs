@@ -0,0 +1,7 @@
+mod store;
+
+use crate::store::Store;
+
+pub fn open() -> Store {
+    Store
+}
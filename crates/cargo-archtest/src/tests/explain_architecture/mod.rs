@@ -0,0 +1,23 @@
+use arch_test_core::Severity;
+
+use crate::domain_values::{CheckOptions, CheckResult};
+use crate::services::{explain_architecture, load_architecture};
+
+#[test]
+fn explain() {
+    let directory_path = "src/tests/check_architecture/severities";
+    assert_eq!(
+        explain_architecture(directory_path, &CheckOptions::default()),
+        CheckResult::default()
+    );
+
+    let mut result = CheckResult::default();
+    let (architecture, module_tree) =
+        load_architecture(directory_path, &CheckOptions::default(), &mut result).unwrap();
+    let explanations = architecture.explain(&module_tree);
+    assert_eq!(explanations.len(), 2);
+    assert_eq!(explanations[0].rule_name(), "NoParentAccess");
+    assert_eq!(explanations[0].severity(), Some(Severity::Warn));
+    assert_eq!(explanations[0].violations(), 1);
+    assert_eq!(explanations[0].matching_uses(), Some(1));
+}
@@ -0,0 +1,4 @@
+mod materials;
+mod services;
+
+fn main() {}
@@ -0,0 +1,5 @@
+use crate::services::Service;
+
+pub struct Material {
+    service: Service,
+}
@@ -0,0 +1,5 @@
+use crate::materials::Material;
+
+pub struct Service;
+
+pub fn serve(material: Material) {}
@@ -0,0 +1,114 @@
+use crate::domain_values::{CheckOptions, GraphCommand, GraphFormat};
+use crate::services::export_graph;
+
+#[test]
+fn dot_graph() {
+    let directory_path = "src/tests/check_architecture/severities";
+    let graph = export_graph(
+        directory_path,
+        &CheckOptions::default(),
+        &GraphCommand::default(),
+    )
+    .unwrap();
+    assert!(graph.starts_with("digraph architecture {\n"));
+    assert!(graph.contains("m1 [label=\"child\"];"));
+    assert!(graph.contains("m1 -> m0 [weight=1, label=\"1\", color=red"));
+
+    let graph = export_graph(
+        directory_path,
+        &CheckOptions::default(),
+        &GraphCommand {
+            depth: Some(0),
+            ..GraphCommand::default()
+        },
+    )
+    .unwrap();
+    assert!(!graph.contains("->"));
+
+    assert!(export_graph(
+        "src/tests/check_architecture/missing",
+        &CheckOptions::default(),
+        &GraphCommand::default()
+    )
+    .is_err());
+}
+
+#[test]
+fn mermaid_and_plantuml_graph() {
+    let directory_path = "src/tests/check_architecture/severities";
+    let graph = export_graph(
+        directory_path,
+        &CheckOptions::default(),
+        &GraphCommand {
+            format: GraphFormat::Mermaid,
+            ..GraphCommand::default()
+        },
+    )
+    .unwrap();
+    assert!(graph.starts_with("flowchart LR\n"));
+    assert!(graph.contains("    subgraph m0_children[\"crate\"]\n        m0[\"crate\"]\n"));
+    assert!(graph.contains("    m1 -->|1| m0\n"));
+    assert!(graph.contains("linkStyle 0 stroke:red"));
+
+    let graph = export_graph(
+        directory_path,
+        &CheckOptions::default(),
+        &GraphCommand {
+            format: GraphFormat::Plantuml,
+            ..GraphCommand::default()
+        },
+    )
+    .unwrap();
+    assert!(graph.starts_with("@startuml\n"));
+    assert!(graph.contains("  [child] as m1\n"));
+    assert!(graph.contains("m1 -[#red,bold]-> m0 : 1\n"));
+    assert!(graph.ends_with("@enduml\n"));
+}
+
+#[test]
+fn graphml_and_gexf_graph() {
+    let directory_path = "src/tests/check_architecture/severities";
+    let graph = export_graph(
+        directory_path,
+        &CheckOptions::default(),
+        &GraphCommand {
+            format: GraphFormat::Graphml,
+            ..GraphCommand::default()
+        },
+    )
+    .unwrap();
+    assert!(graph.contains("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">"));
+    assert!(graph.contains("<data key=\"path\">crate::child</data>"));
+    assert!(graph.contains("<data key=\"kinds\">Function</data>"));
+
+    let graph = export_graph(
+        directory_path,
+        &CheckOptions::default(),
+        &GraphCommand {
+            format: GraphFormat::Gexf,
+            items: true,
+            ..GraphCommand::default()
+        },
+    )
+    .unwrap();
+    assert!(graph.contains("<node id=\"i0\" label=\"child_exec\">"));
+    assert!(graph.contains("<attvalue for=\"kind\" value=\"Function\"/>"));
+    assert!(graph.contains("<edge id=\"e0\" source=\"i0\" target=\"i2\" weight=\"1\">"));
+}
+
+#[test]
+fn structurizr_graph() {
+    let graph = export_graph(
+        "src/tests/export_graph/layers",
+        &CheckOptions::default(),
+        &GraphCommand {
+            format: GraphFormat::Structurizr,
+            ..GraphCommand::default()
+        },
+    )
+    .unwrap();
+    assert!(graph.starts_with("workspace \"layers\" {\n"));
+    assert!(graph.contains("                l1 = component \"services\" \"2 items\" \"Rust\"\n"));
+    assert!(graph.contains("        l0 -> l1 \"1 use\" \"Rust\"\n"));
+    assert!(graph.contains("        l1 -> l0 \"1 use\" \"Rust\" \"Violation\"\n"));
+}
@@ -0,0 +1,45 @@
+use std::fs;
+
+use crate::domain_values::{CheckOptions, ReportCommand};
+use crate::services::export_report;
+
+#[test]
+fn html_report() {
+    let output_directory = std::env::temp_dir().join("archtest_html_report");
+    let report_command = ReportCommand {
+        html: output_directory.to_string_lossy().to_string(),
+    };
+    let result = export_report(
+        "src/tests/check_architecture/severities",
+        &CheckOptions::default(),
+        &report_command,
+    );
+    assert_eq!(result.errors, 0);
+
+    let html = fs::read_to_string(
+        output_directory
+            .join("src/tests/check_architecture/severities")
+            .join("index.html"),
+    )
+    .unwrap();
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(!html.contains("/*ARCHTEST_DATA*/"));
+    assert!(!html.contains("<script src") && !html.contains("<link"));
+    let data_start = html.find("type=\"application/json\">").unwrap() + 24;
+    let data_end = data_start + html[data_start..].find("</script>").unwrap();
+    let data: serde_json::Value = serde_json::from_str(&html[data_start..data_end]).unwrap();
+    assert_eq!(data["report"]["rules"][0]["name"], "NoParentAccess");
+    assert_eq!(data["report"]["rules"][0]["status"], "warned");
+    assert_eq!(data["report"]["rules"][1]["status"], "passed");
+    assert!(data["report"]["warnings"][0]["diagnostic"]
+        .as_str()
+        .unwrap()
+        .starts_with("warning[A0004]"));
+    assert_eq!(data["graph"]["nodes"][1]["path"], "crate::child");
+    assert!(data["graph"]["nodes"][1]["file"]
+        .as_str()
+        .unwrap()
+        .ends_with("child.rs"));
+    assert_eq!(data["graph"]["edges"][0]["highlighted"], true);
+    let _ = fs::remove_dir_all(output_directory);
+}
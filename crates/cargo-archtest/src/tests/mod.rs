@@ -1,2 +1,8 @@
+mod baseline;
 mod check_architecture;
+mod dump_model;
+mod explain_architecture;
+mod export_graph;
+mod export_report;
 mod parse_specification;
+mod query_modules;
@@ -1,10 +1,107 @@
 use std::path::Path;
 
-use crate::services::parse_specification;
+use arch_test_core::ModuleTree;
+
+use crate::domain_values::{Failure, SpecificationProblem};
+use crate::services::{build_architecture, read_specification, validate_specification};
 
 #[test]
 fn parse() {
-    let _specification =
-        parse_specification(Path::new("src/tests/parse_specification/architecture.json")).unwrap();
+    let specification =
+        read_specification(Path::new("src/tests/parse_specification/architecture.json")).unwrap();
+    let _architecture = build_architecture(specification);
     // Not exactly sure how to assert it, but the important stuff is that most lines are run through and none panic
 }
+
+#[test]
+fn invalid_json() {
+    match read_specification(Path::new("src/tests/parse_specification/invalid_json.json")) {
+        Err(Failure::SpecificationCouldNotBeParsed(problems)) => {
+            assert_eq!(problems.len(), 1);
+            assert!(matches!(
+                problems[0],
+                SpecificationProblem::InvalidJson {
+                    line: 5,
+                    column: 3,
+                    ..
+                }
+            ));
+        }
+        _ => panic!("Expected the specification to be invalid"),
+    }
+}
+
+#[test]
+fn invalid_entries() {
+    match read_specification(Path::new(
+        "src/tests/parse_specification/invalid_entries.json",
+    )) {
+        Err(Failure::SpecificationCouldNotBeParsed(problems)) => {
+            let locations: Vec<String> = problems
+                .iter()
+                .map(|problem| match problem {
+                    SpecificationProblem::InvalidEntry { location, .. } => location.clone(),
+                    _ => panic!("Expected only invalid entries"),
+                })
+                .collect();
+            assert_eq!(
+                locations,
+                vec!["access_rules[1]", "access_rules[2]", "access_rules[3]"]
+            );
+            assert!(matches!(
+                &problems[0],
+                SpecificationProblem::InvalidEntry {
+                    suggestion: Some(suggestion),
+                    ..
+                } if suggestion == "NoParentAccess"
+            ));
+        }
+        _ => panic!("Expected the specification to be invalid"),
+    }
+}
+
+#[test]
+fn validate() {
+    let specification =
+        read_specification(Path::new("src/tests/parse_specification/validation.json")).unwrap();
+    let module_tree = ModuleTree::new("src/tests/check_architecture/severities/src/main.rs");
+    let problems = validate_specification(&specification, &module_tree);
+    for problem in problems.iter() {
+        println!("{}", problem);
+    }
+    assert!(problems.contains(&SpecificationProblem::NeverMatches {
+        location: "access_rules[0]".to_owned(),
+        layer: "services".to_owned(),
+    }));
+    assert!(problems.contains(&SpecificationProblem::UnknownLayer {
+        location: "access_rules[1].AllOf[1]".to_owned(),
+        layer: "servces".to_owned(),
+        suggestion: Some("services".to_owned()),
+    }));
+    assert!(
+        problems.contains(&SpecificationProblem::ContradictingRules {
+            location: "access_rules[0]".to_owned(),
+            other_location: "access_rules[1].AllOf[0]".to_owned(),
+            accessor: "child".to_owned(),
+            accessed: "ghost".to_owned(),
+        })
+    );
+    assert!(!problems.iter().any(|problem| matches!(
+        problem,
+        SpecificationProblem::ContradictingRules { accessed, .. } if accessed == "services"
+    )));
+    assert!(problems.contains(&SpecificationProblem::NeverMatches {
+        location: "access_rules[2]".to_owned(),
+        layer: "crate::chil".to_owned(),
+    }));
+    assert!(
+        problems.contains(&SpecificationProblem::ModulePathNotSupported {
+            location: "access_rules[3]".to_owned(),
+            path: "crate::child".to_owned(),
+        })
+    );
+    assert_eq!(
+        problems.iter().filter(|problem| problem.is_error()).count(),
+        2
+    );
+}
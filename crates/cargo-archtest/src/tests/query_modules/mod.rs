@@ -0,0 +1,50 @@
+use crate::domain_values::{CheckOptions, QueryCommand};
+use crate::services::query_modules;
+
+const PROJECT: &str = "src/tests/export_graph/layers";
+
+fn query(query_command: QueryCommand) -> String {
+    query_modules(PROJECT, &CheckOptions::default(), &query_command).unwrap()
+}
+
+#[test]
+fn module_queries() {
+    let services = QueryCommand {
+        module: "crate::services".to_owned(),
+        ..QueryCommand::default()
+    };
+    assert_eq!(query(services.clone()), "crate::materials\n");
+    assert_eq!(
+        query(QueryCommand {
+            dependents: true,
+            transitive: true,
+            ..services.clone()
+        }),
+        "crate::materials\n"
+    );
+    assert_eq!(
+        query(QueryCommand {
+            path_to: Some("crate::materials".to_owned()),
+            ..services.clone()
+        }),
+        "crate::services -> crate::materials\n"
+    );
+    assert_eq!(
+        query(QueryCommand {
+            items: true,
+            ..services
+        }),
+        "crate::services::Service\ncrate::services::serve\n"
+    );
+
+    let result = query_modules(
+        PROJECT,
+        &CheckOptions::default(),
+        &QueryCommand {
+            module: "crate::material".to_owned(),
+            ..QueryCommand::default()
+        },
+    )
+    .unwrap_err();
+    assert_eq!(result.errors, 1);
+}
@@ -1,3 +1,5 @@
+fn main() {}
+
 #[cfg(test)]
 mod test {
     extern crate arch_test_core;
@@ -6,7 +8,38 @@ mod test {
         MayNotAccess, MayNotBeAccessedBy, MayOnlyAccess, MayOnlyBeAccessedBy,
         NoLayerCyclicDependencies, NoModuleCyclicDependencies, NoParentAccess,
     };
-    use arch_test_core::{hash_set, Architecture, ModuleTree};
+    use arch_test_core::{
+        hash_set, AccessRule, Architecture, ModuleTree, RuleViolation, RuleViolationType,
+    };
+    use std::collections::HashSet;
+
+    /// Forbids a layer to use a certain item, written against the public query API only
+    #[derive(Debug)]
+    struct LayerMayNotUseItem {
+        layer: String,
+        item: String,
+    }
+
+    impl AccessRule for LayerMayNotUseItem {
+        fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
+            let graph = module_tree.graph_view();
+            match graph.dependencies().find(|use_relation| {
+                graph.module_in_layer(use_relation.using_object().node_index(), &self.layer)
+                    && use_relation.used_object().usable_object().object_name() == &self.item
+            }) {
+                Some(use_relation) => Err(RuleViolation::new(
+                    RuleViolationType::SingleLocation,
+                    self.violated_rule(),
+                    vec![use_relation],
+                )),
+                None => Ok(()),
+            }
+        }
+
+        fn validate(&self, layer_names: &HashSet<String>) -> bool {
+            layer_names.contains(&self.layer)
+        }
+    }
 
     #[test]
     fn test_architecture() {
@@ -47,6 +80,25 @@ mod test {
         assert!(architecture.validate_access_rules().is_ok());
         assert!(architecture.check_access_rules(&module_tree).is_ok());
     }
-}
 
-fn main() {}
+    #[test]
+    fn custom_access_rule() {
+        let module_tree = ModuleTree::new("../../crates/arch_test_core/src/lib.rs");
+        let architecture = Architecture::new(hash_set!["analyzer".to_owned()]).with_access_rule(
+            LayerMayNotUseItem {
+                layer: "analyzer".to_owned(),
+                item: "ModuleTree".to_owned(),
+            },
+        );
+        assert!(architecture.validate_access_rules().is_ok());
+        assert!(architecture.check_access_rules(&module_tree).is_err());
+
+        let architecture = Architecture::new(hash_set!["parser".to_owned()]).with_access_rule(
+            LayerMayNotUseItem {
+                layer: "parser".to_owned(),
+                item: "Architecture".to_owned(),
+            },
+        );
+        assert!(architecture.check_access_rules(&module_tree).is_ok());
+    }
+}